@@ -0,0 +1,238 @@
+use aoc_common::{Day, ParseError};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Summary statistics over a set of timings, in seconds.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    pub fn new(timings: &[Duration]) -> Self {
+        let mut secs: Vec<f64> = timings.iter().map(Duration::as_secs_f64).collect();
+        secs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let n = secs.len() as f64;
+        let mean = secs.iter().sum::<f64>() / n;
+        let variance = secs.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / n;
+
+        let mid = secs.len() / 2;
+        let median = if secs.len() % 2 == 1 {
+            secs[mid]
+        } else {
+            (secs[mid - 1] + secs[mid]) / 2.0
+        };
+
+        Self {
+            mean,
+            median,
+            stddev: variance.sqrt(),
+        }
+    }
+}
+
+/// The timings of every phase of one day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub iterations: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl DayReport {
+    fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+/// Times parsing and both parts of a day separately.
+///
+/// The day runs `warmup` times untimed before the `iterations` timed runs.
+pub fn bench(
+    day: &Day,
+    input: &str,
+    warmup: usize,
+    iterations: usize,
+) -> Result<DayReport, ParseError> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+
+    for i in 0..warmup + iterations {
+        let start = Instant::now();
+        let puzzle = black_box(day.parse(input)?);
+        let parsed = Instant::now();
+        black_box(puzzle.part1());
+        let solved1 = Instant::now();
+        black_box(puzzle.part2());
+        let solved2 = Instant::now();
+
+        if i >= warmup {
+            parse.push(parsed - start);
+            part1.push(solved1 - parsed);
+            part2.push(solved2 - solved1);
+        }
+    }
+
+    Ok(DayReport {
+        day: day.day,
+        iterations,
+        parse: Stats::new(&parse),
+        part1: Stats::new(&part1),
+        part2: Stats::new(&part2),
+    })
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+}
+
+/// A phase that got slower than the baseline allows.
+#[derive(Debug)]
+pub struct Regression {
+    pub day: u8,
+    pub phase: &'static str,
+    pub baseline: f64,
+    pub current: f64,
+}
+
+impl Report {
+    /// Finds every phase whose mean is more than `threshold` (as a fraction) slower than in the
+    /// baseline. Days missing from the baseline are never regressions.
+    pub fn regressions(&self, baseline: &Report, threshold: f64) -> Vec<Regression> {
+        let mut ret = vec![];
+
+        for day in &self.days {
+            let old = match baseline.days.iter().find(|d| d.day == day.day) {
+                Some(old) => old,
+                None => continue,
+            };
+
+            for ((phase, current), (_, baseline)) in day.phases().iter().zip(old.phases().iter()) {
+                if current.mean > baseline.mean * (1.0 + threshold) {
+                    ret.push(Regression {
+                        day: day.day,
+                        phase,
+                        baseline: baseline.mean,
+                        current: current.mean,
+                    });
+                }
+            }
+        }
+
+        ret
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut ret = String::new();
+
+        ret.push_str("| Day | Phase | Mean | Median | Std. dev. |\n");
+        ret.push_str("|----:|:------|-----:|-------:|----------:|\n");
+
+        for day in &self.days {
+            for (phase, stats) in day.phases().iter() {
+                writeln!(
+                    ret,
+                    "| {} | {} | {} | {} | {} |",
+                    day.day,
+                    phase,
+                    format_secs(stats.mean),
+                    format_secs(stats.median),
+                    format_secs(stats.stddev),
+                )
+                .unwrap();
+            }
+        }
+
+        ret
+    }
+}
+
+/// Formats a duration in seconds with a unit that suits its size.
+pub fn format_secs(secs: f64) -> String {
+    if secs >= 1.0 {
+        format!("{:.3} s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.3} ms", secs * 1e3)
+    } else {
+        format!("{:.3} µs", secs * 1e6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(mean: f64) -> Stats {
+        Stats {
+            mean,
+            median: mean,
+            stddev: 0.0,
+        }
+    }
+
+    fn day(day: u8, parse: f64, part1: f64, part2: f64) -> DayReport {
+        DayReport {
+            day,
+            iterations: 1,
+            parse: stats(parse),
+            part1: stats(part1),
+            part2: stats(part2),
+        }
+    }
+
+    fn millis(timings: &[u64]) -> Vec<Duration> {
+        timings.iter().map(|&t| Duration::from_millis(t)).collect()
+    }
+
+    #[test]
+    fn summarizes_timings() {
+        let odd = Stats::new(&millis(&[30, 10, 20]));
+        assert!((odd.mean - 0.020).abs() < 1e-9);
+        assert!((odd.median - 0.020).abs() < 1e-9);
+        assert!((odd.stddev - (2.0f64 / 3.0).sqrt() / 100.0).abs() < 1e-9);
+
+        let even = Stats::new(&millis(&[40, 10, 10, 20]));
+        assert!((even.mean - 0.020).abs() < 1e-9);
+        assert!((even.median - 0.015).abs() < 1e-9);
+        assert!((even.stddev - 0.0122474487).abs() < 1e-9);
+
+        let one = Stats::new(&millis(&[5]));
+        assert_eq!((one.median, one.stddev), (0.005, 0.0));
+    }
+
+    #[test]
+    fn finds_regressions() {
+        let baseline = Report {
+            days: vec![day(1, 1.0, 1.0, 1.0), day(2, 1.0, 1.0, 1.0)],
+        };
+        let current = Report {
+            days: vec![
+                // Just over and just under 10% slower
+                day(1, 1.11, 1.09, 0.5),
+                day(2, 1.0, 1.0, 1.2),
+                // Not in the baseline, so there's nothing to compare to
+                day(3, 9.0, 9.0, 9.0),
+            ],
+        };
+
+        let found: Vec<(u8, &str)> = current
+            .regressions(&baseline, 0.10)
+            .iter()
+            .map(|r| (r.day, r.phase))
+            .collect();
+        assert_eq!(found, [(1, "parse"), (2, "part 2")]);
+    }
+}
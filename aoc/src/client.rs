@@ -0,0 +1,181 @@
+use serde::Deserialize;
+use std::env;
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const YEAR: u16 = 2020;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// How to reach the Advent of Code website.
+///
+/// This comes from a TOML config file, `aoc.toml` in the current directory unless `AOC_CONFIG`
+/// points somewhere else:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// ```
+///
+/// The `AOC_SESSION` and `AOC_BASE_URL` environment variables take precedence over the file.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn load() -> Result<Self, Box<dyn Error>> {
+        let path = env::var_os("AOC_CONFIG").map_or_else(|| PathBuf::from("aoc.toml"), Into::into);
+        let mut config = Self::from_file(&path)?;
+
+        if let Ok(session) = env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+
+        if let Ok(base_url) = env::var("AOC_BASE_URL") {
+            config.base_url = Some(base_url);
+        }
+
+        Ok(config)
+    }
+
+    /// Reads a config file, or returns an empty config if there is no such file.
+    pub fn from_file(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// A logged in client for the Advent of Code website.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(config: &Config) -> Result<Self, Box<dyn Error>> {
+        let session = config
+            .session
+            .clone()
+            .ok_or("no session token, set AOC_SESSION or `session` in aoc.toml")?;
+        let base_url = config.base_url.as_deref().unwrap_or(DEFAULT_BASE_URL);
+
+        Ok(Self {
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent("adventofcode-2020 aoc runner")
+                .build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+        })
+    }
+
+    fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, YEAR, day, page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Downloads a day's puzzle input.
+    pub fn input(&self, day: u8) -> Result<String, Box<dyn Error>> {
+        let response = self
+            .agent
+            .get(&self.url(day, "input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+
+        Ok(response.into_string()?)
+    }
+
+    /// Submits an answer, returning the page the website answers with.
+    pub fn answer(&self, day: u8, part: u8, answer: &str) -> Result<String, Box<dyn Error>> {
+        let response = self
+            .agent
+            .post(&self.url(day, "answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])?;
+
+        Ok(response.into_string()?)
+    }
+}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+/// Makes sure a day's input is saved at `path`, only downloading it if it isn't there yet.
+pub fn fetch(client: &Client, day: u8, path: &Path) -> Result<Fetched, Box<dyn Error>> {
+    if path.exists() {
+        return Ok(Fetched::Cached);
+    }
+
+    let input = client.input(day)?;
+
+    // Write to a temporary file first, so that an interrupted fetch doesn't look cached
+    let partial = path.with_extension("part");
+    fs::write(&partial, input)?;
+    fs::rename(&partial, path)?;
+
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub::{self, Stub};
+
+    fn client(stub: &Stub) -> Client {
+        Client::new(&Config {
+            session: Some("s3cr3t".to_string()),
+            base_url: Some(stub.url()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn downloads_once() {
+        let stub = Stub::start(vec![(200, "1721\n979\n".to_string())]);
+        let dir = stub::temp_dir("downloads_once");
+        let path = dir.join("input.txt");
+        let client = client(&stub);
+
+        assert_eq!(fetch(&client, 1, &path).unwrap(), Fetched::Downloaded);
+        assert_eq!(fetch(&client, 1, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2020/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=s3cr3t"));
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let stub = Stub::start(vec![(404, "Not found".to_string())]);
+        let dir = stub::temp_dir("failed_downloads_are_not_cached");
+        let path = dir.join("input.txt");
+
+        assert!(fetch(&client(&stub), 25, &path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn needs_a_session() {
+        assert!(Client::new(&Config::default()).is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::Day;
+use std::path::PathBuf;
+
+/// Every day with a solution, in calendar order.
+pub const DAYS: &[Day] = &[
+    day01::DAY,
+    day02::DAY,
+    day03::DAY,
+    day04::DAY,
+    day05::DAY,
+    day06::DAY,
+    day07::DAY,
+    day08::DAY,
+    day09::DAY,
+    day10::DAY,
+    day11::DAY,
+    day12::DAY,
+    day13::DAY,
+    day14::DAY,
+    day15::DAY,
+    day16::DAY,
+    day17::DAY,
+    day18::DAY,
+    day19::DAY,
+    day20::DAY,
+    day21::DAY,
+    day22::DAY,
+    day23::DAY,
+    day24::DAY,
+    day25::DAY,
+];
+
+/// Looks up a day's solution by its number.
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+/// The directory holding a day's crate, its input and anything else that belongs to it.
+pub fn dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("day{:02}", day))
+}
+
+/// Where a day's puzzle input is kept.
+pub fn input_file(day: u8) -> PathBuf {
+    dir(day).join("input.txt")
+}
+
+/// Where every answer submitted for a day is logged, see [`crate::submit::Log`].
+pub fn submissions_file(day: u8) -> PathBuf {
+    dir(day).join("submissions.jsonl")
+}
+
+/// Where a day's known answers are kept, see [`crate::verify::Answers`].
+pub fn answers_file(day: u8) -> PathBuf {
+    dir(day).join("answers.toml")
+}
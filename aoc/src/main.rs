@@ -0,0 +1,431 @@
+use aoc::bench::{self, Report};
+use aoc::client::{self, Client, Config, Fetched};
+use aoc::days;
+use aoc::runner::{self, Status as RunStatus};
+use aoc::scaffold;
+use aoc::submit::{self, Log, Verdict};
+use aoc::verify::{self, Status};
+use aoc_common::args::Args;
+use aoc_common::input;
+use aoc_common::progress::Progress;
+use aoc_common::Answer;
+use std::error::Error;
+use std::fs;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str = "\
+Usage: aoc run --day N [--part P] [--input PATH] [--timeout SECS]
+       aoc run --all [--threads T] [--timeout SECS]
+       aoc bench [--day N] [--input PATH] [--iterations K] [--warmup W]
+                 [--json PATH] [--markdown PATH] [--baseline PATH] [--threshold PCT]
+       aoc verify [--day N]
+       aoc fetch --day N
+       aoc submit --day N --part P [--input PATH]
+       aoc new --day N
+
+run    Runs a day's solution and prints one `day=N part=P answer=...` line per
+       part. Both parts are run unless --part is given. The puzzle input is read
+       from PATH, or from stdin if PATH is `-` or missing.
+       With --all, runs every day with a dayNN/input.txt on T threads (default
+       one per CPU) and prints a table of the answers and how long each part
+       took. A day that panics is reported as failed without stopping the
+       others, and the command fails if any day did.
+       Days that take longer than a second show how far along they are, as
+       far as they report it. With --timeout, a day still running after SECS
+       seconds is stopped and reported as timed out. A day that doesn't report
+       its progress can't be stopped, so it's left running in the background
+       until the command exits.
+       When built with `--features memory`, also shows the peak heap size and
+       number of allocations of parsing and each part.
+
+bench  Times parsing and each part separately over K iterations (default 10),
+       for one day or for every day with a dayNN/input.txt. Writes the results
+       to report.json and report.md unless told otherwise. With --baseline, a
+       previous JSON report, it fails if any mean got more than PCT percent
+       (default 10) slower. Build with --release for meaningful numbers.
+
+verify Runs one day, or every day with a dayNN/input.txt, and checks the
+       answers against the ones in dayNN/answers.toml. Fails if any answer
+       doesn't match, or if a day fails to parse or panics; parts with no known
+       answer are reported as missing, and parts with no puzzle are left out.
+
+fetch  Downloads a day's puzzle input into dayNN/input.txt, unless it's already
+       there. Needs the session cookie of a logged in browser, from AOC_SESSION
+       or `session` in aoc.toml (or the file in AOC_CONFIG). The website can be
+       swapped out with AOC_BASE_URL or `base_url`.
+
+submit Solves a part, on dayNN/input.txt unless told otherwise, and submits
+       the answer to the website, set up as for fetch. Every attempt is logged
+       in dayNN/submissions.jsonl, and answers the log shows to be wrong are
+       never sent again. Fails unless the answer was right.
+
+new    Creates the dayNN crate from the templates in aoc/templates, and adds it
+       to the runner. Run it from the root of the workspace.";
+
+fn timeout(args: &Args) -> Result<Option<Duration>, Box<dyn Error>> {
+    match args.value::<f64>("timeout")? {
+        Some(secs) => {
+            Ok(Some(Duration::try_from_secs_f64(secs).map_err(|_| {
+                format!("invalid value for --timeout: {}", secs)
+            })?))
+        }
+        None => Ok(None),
+    }
+}
+
+fn show_progress(day: u8, elapsed: Duration, progress: &Progress) {
+    let elapsed = bench::format_secs(elapsed.as_secs_f64());
+
+    match (progress.done(), progress.total()) {
+        (done, Some(total)) => eprintln!(
+            "[.] Day {} has been running for {}, at {} of {}",
+            day, elapsed, done, total
+        ),
+        (0, None) => eprintln!("[.] Day {} has been running for {}", day, elapsed),
+        (done, None) => eprintln!(
+            "[.] Day {} has been running for {}, at {}",
+            day, elapsed, done
+        ),
+    }
+}
+
+/// Runs `f` without printing panics as they happen, since the days that panic are reported as
+/// failed anyway.
+fn quietly<T, F: FnOnce() -> T>(f: F) -> T {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let ret = f();
+    panic::set_hook(hook);
+    ret
+}
+
+fn run_all(args: &Args) -> Result<(), Box<dyn Error>> {
+    let timeout = timeout(args)?;
+    let threads = match args.value("threads")? {
+        Some(threads) => threads,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+
+    let mut todo = vec![];
+    for solution in days::DAYS {
+        let path = days::input_file(solution.day);
+        if path.exists() {
+            todo.push((solution, path));
+        } else {
+            eprintln!("[!] Skipping day {}, no input", solution.day);
+        }
+    }
+
+    let runs = quietly(|| {
+        runner::supervise(timeout, show_progress, |s| {
+            runner::run_all(s, &todo, threads)
+        })
+    });
+
+    print!("{}", runner::table(&runs));
+
+    let failed = runs.iter().filter(|r| r.status != RunStatus::Ok).count();
+    if failed > 0 {
+        return Err(format!("{} days failed", failed).into());
+    }
+
+    Ok(())
+}
+
+fn run(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(&["day", "part", "input", "timeout", "threads"], &["all"])?;
+
+    if args.flag("all") {
+        return run_all(args);
+    }
+
+    let day: u8 = args.required("day")?;
+    let parts = match args.value("part")? {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let solution =
+        days::find(day).ok_or_else(|| format!("there is no solution for day {}", day))?;
+    let timeout = timeout(args)?;
+    let path = args.get("input").map(Path::new);
+    let input = input::read(path)?;
+    let name = input::name(path).to_path_buf();
+
+    let run = quietly(|| {
+        runner::supervise(timeout, show_progress, |s| {
+            let parts = parts.clone();
+            s.run(day, move |progress| {
+                runner::run_day(solution, &input, &name, &parts, progress)
+            })
+        })
+    });
+
+    if let Some(heap) = run.parse_heap {
+        eprintln!("[.] Parsing used {}", heap);
+    }
+
+    for (part, solved) in [(1, &run.part1), (2, &run.part2)].iter() {
+        if let Some(solved) = solved {
+            println!("day={} part={} answer={}", day, part, solved.answer);
+
+            if let Some(heap) = solved.heap {
+                eprintln!("[.] Part {} used {}", part, heap);
+            }
+        } else if run.status == RunStatus::Ok && parts.contains(part) {
+            eprintln!("[.] There's no puzzle in part {}", part);
+        }
+    }
+
+    match run.status {
+        RunStatus::Ok => Ok(()),
+        RunStatus::Failed(reason) => Err(reason.into()),
+        RunStatus::TimedOut => Err(format!("day {} timed out", day).into()),
+    }
+}
+
+fn bench(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(
+        &[
+            "day",
+            "input",
+            "iterations",
+            "warmup",
+            "json",
+            "markdown",
+            "baseline",
+            "threshold",
+        ],
+        &[],
+    )?;
+
+    let iterations = args.value("iterations")?.unwrap_or(10);
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".into());
+    }
+    let warmup = args.value("warmup")?.unwrap_or(1);
+    let threshold: f64 = args.value("threshold")?.unwrap_or(10.0);
+
+    let baseline: Option<Report> = match args.get("baseline") {
+        Some(path) => Some(serde_json::from_str(&fs::read_to_string(path)?)?),
+        None => None,
+    };
+
+    let mut todo = vec![];
+    match args.value("day")? {
+        Some(day) => {
+            let solution =
+                days::find(day).ok_or_else(|| format!("there is no solution for day {}", day))?;
+            let input = args
+                .get("input")
+                .map_or_else(|| days::input_file(day), Into::into);
+            todo.push((solution, input));
+        }
+        None => {
+            for solution in days::DAYS {
+                todo.push((solution, days::input_file(solution.day)));
+            }
+        }
+    }
+
+    let mut report = Report::default();
+
+    for (solution, path) in todo {
+        if !path.exists() {
+            eprintln!("[!] Skipping day {}, no input", solution.day);
+            continue;
+        }
+
+        eprintln!("--- Benchmarking day {} ---", solution.day);
+        let input = fs::read_to_string(&path)?;
+        let day =
+            bench::bench(solution, &input, warmup, iterations).map_err(|e| e.with_file(&path))?;
+
+        eprintln!(
+            "parse {}, part 1 {}, part 2 {}",
+            bench::format_secs(day.parse.mean),
+            bench::format_secs(day.part1.mean),
+            bench::format_secs(day.part2.mean),
+        );
+
+        report.days.push(day);
+    }
+
+    fs::write(
+        args.get("json").unwrap_or("report.json"),
+        serde_json::to_string_pretty(&report)?,
+    )?;
+    fs::write(
+        args.get("markdown").unwrap_or("report.md"),
+        report.to_markdown(),
+    )?;
+
+    if let Some(baseline) = baseline {
+        let regressions = report.regressions(&baseline, threshold / 100.0);
+
+        for r in &regressions {
+            eprintln!(
+                "[!] Day {} {} regressed: {} -> {}",
+                r.day,
+                r.phase,
+                bench::format_secs(r.baseline),
+                bench::format_secs(r.current),
+            );
+        }
+
+        if !regressions.is_empty() {
+            return Err(format!(
+                "{} phases regressed by more than {}%",
+                regressions.len(),
+                threshold
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
+
+fn verify(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(&["day"], &[])?;
+
+    let todo: Vec<_> = match args.value("day")? {
+        Some(day) => {
+            vec![days::find(day).ok_or_else(|| format!("there is no solution for day {}", day))?]
+        }
+        None => days::DAYS.iter().collect(),
+    };
+
+    let mut checked = vec![];
+
+    println!("Day | Part | Status  | Answer");
+    println!("----|------|---------|-------");
+
+    quietly(|| {
+        for solution in todo {
+            let path = days::input_file(solution.day);
+            if !path.exists() {
+                eprintln!("[!] Skipping day {}, no input", solution.day);
+                continue;
+            }
+
+            let answers = days::answers_file(solution.day);
+            for outcome in verify::verify_files(solution, &path, &answers) {
+                let status = outcome.status();
+                let answer = outcome
+                    .answer
+                    .as_ref()
+                    .map_or("-".to_string(), |a| a.to_string());
+                print!(
+                    "{:>3} | {:>4} | {:<7} | {}",
+                    outcome.day, outcome.part, status, answer
+                );
+
+                if status == Status::Fail {
+                    match (&outcome.answer, &outcome.expected) {
+                        (Err(reason), _) => print!(" ({})", reason),
+                        (Ok(_), Some(expected)) => print!(" (expected {})", expected),
+                        (Ok(_), None) => {}
+                    }
+                }
+
+                println!();
+                checked.push(outcome);
+            }
+        }
+    });
+
+    Ok(verify::check(&checked)?)
+}
+
+fn fetch(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(&["day"], &[])?;
+
+    let day: u8 = args.required("day")?;
+    let path = days::input_file(day);
+
+    if !days::dir(day).is_dir() {
+        return Err(format!("there is no directory for day {}", day).into());
+    }
+
+    let client = Client::new(&Config::load()?)?;
+
+    match client::fetch(&client, day, &path)? {
+        Fetched::Cached => eprintln!("Day {} is already in {}", day, path.display()),
+        Fetched::Downloaded => eprintln!("Fetched day {} into {}", day, path.display()),
+    }
+
+    Ok(())
+}
+
+fn submit(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(&["day", "part", "input"], &[])?;
+
+    let day: u8 = args.required("day")?;
+    let part: u8 = args.required("part")?;
+
+    let solution =
+        days::find(day).ok_or_else(|| format!("there is no solution for day {}", day))?;
+    let path = args
+        .get("input")
+        .map_or_else(|| days::input_file(day), Into::into);
+    let input =
+        fs::read_to_string(&path).map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+    let puzzle = solution.parse(&input).map_err(|e| e.with_file(&path))?;
+    let answer = puzzle
+        .part(part)
+        .ok_or_else(|| format!("there is no part {}", part))?;
+    if answer == Answer::NoPuzzle {
+        return Err(format!("there's no puzzle to answer in day {} part {}", day, part).into());
+    }
+
+    let client = Client::new(&Config::load()?)?;
+    let mut log = Log::load(&days::submissions_file(day))?;
+
+    eprintln!("Submitting {} for day {} part {}", answer, day, part);
+    let (verdict, text) = submit::submit(&client, &mut log, day, part, &answer)?;
+    eprintln!("{}", text);
+
+    if verdict != Verdict::Right {
+        return Err(format!("the answer was {}", verdict).into());
+    }
+
+    Ok(())
+}
+
+fn new(args: &Args) -> Result<(), Box<dyn Error>> {
+    args.check(&["day"], &[])?;
+
+    let day: u8 = args.required("day")?;
+
+    scaffold::new_day(Path::new("."), day)?;
+    eprintln!("Created {}", days::dir(day).display());
+
+    Ok(())
+}
+
+fn main() {
+    let args = Args::from_env();
+
+    let result = match args.command() {
+        Some("run") => run(&args),
+        Some("bench") => bench(&args),
+        Some("verify") => verify(&args),
+        Some("fetch") => fetch(&args),
+        Some("submit") => submit(&args),
+        Some("new") => new(&args),
+        _ => {
+            eprintln!("{}", USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,170 @@
+//! Heap usage of the solutions, measured by a counting global allocator.
+//!
+//! The allocator is only installed with the `memory` feature, as it slows every allocation down a
+//! little. Without it, nothing is measured.
+
+use std::fmt;
+
+/// How much a piece of code used the heap.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// The most bytes it had allocated at once, on top of what was already allocated.
+    pub peak_bytes: usize,
+    pub allocations: u64,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(&format!(
+            "{} peak, {} allocs",
+            format_bytes(self.peak_bytes),
+            self.allocations
+        ))
+    }
+}
+
+/// Formats a number of bytes with a binary unit that suits its size.
+pub fn format_bytes(bytes: usize) -> String {
+    let bytes = bytes as f64;
+
+    if bytes >= (1 << 30) as f64 {
+        format!("{:.1} GiB", bytes / (1 << 30) as f64)
+    } else if bytes >= (1 << 20) as f64 {
+        format!("{:.1} MiB", bytes / (1 << 20) as f64)
+    } else if bytes >= (1 << 10) as f64 {
+        format!("{:.1} KiB", bytes / (1 << 10) as f64)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+#[cfg(feature = "memory")]
+mod counting {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    // Counted per thread, so that days running side by side don't get mixed up. Memory freed on
+    // another thread than the one it was allocated on makes these drift, which is why they're
+    // signed, but the solutions don't do that.
+    thread_local! {
+        pub static CURRENT: Cell<isize> = const { Cell::new(0) };
+        pub static PEAK: Cell<isize> = const { Cell::new(0) };
+        pub static ALLOCATIONS: Cell<u64> = const { Cell::new(0) };
+    }
+
+    fn allocated(size: usize) {
+        let current = CURRENT.get() + size as isize;
+        CURRENT.set(current);
+        PEAK.set(PEAK.get().max(current));
+        ALLOCATIONS.set(ALLOCATIONS.get() + 1);
+    }
+
+    fn freed(size: usize) {
+        CURRENT.set(CURRENT.get() - size as isize);
+    }
+
+    pub struct Counting;
+
+    unsafe impl GlobalAlloc for Counting {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                allocated(layout.size());
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            freed(layout.size());
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new = System.realloc(ptr, layout, new_size);
+            if !new.is_null() {
+                freed(layout.size());
+                allocated(new_size);
+            }
+            new
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+}
+
+/// Runs `f`, measuring how much it used the heap on this thread, if that's being measured.
+#[cfg(feature = "memory")]
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    use counting::{ALLOCATIONS, CURRENT, PEAK};
+
+    let start = CURRENT.get();
+    let outer_peak = PEAK.replace(start);
+    let allocations = ALLOCATIONS.get();
+
+    let ret = f();
+
+    let peak = PEAK.get();
+    let usage = Usage {
+        peak_bytes: (peak - start).max(0) as usize,
+        allocations: ALLOCATIONS.get() - allocations,
+    };
+
+    // Anything measuring around this still needs to know about its peak
+    PEAK.set(outer_peak.max(peak));
+
+    (ret, Some(usage))
+}
+
+/// Runs `f`, measuring how much it used the heap on this thread, if that's being measured.
+#[cfg(not(feature = "memory"))]
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Option<Usage>) {
+    (f(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+    }
+
+    #[cfg(feature = "memory")]
+    #[test]
+    fn measures_allocations() {
+        let ((), outer) = measure(|| {
+            let (v, inner) = measure(|| vec![0u8; 1 << 20]);
+            let inner = inner.unwrap();
+
+            assert!(inner.peak_bytes >= 1 << 20);
+            assert_eq!(inner.allocations, 1);
+            drop(v);
+
+            let mut v = Vec::with_capacity(1);
+            v.push(1u64);
+            v.reserve(1 << 10);
+        });
+        let outer = outer.unwrap();
+
+        assert!(outer.peak_bytes >= 1 << 20);
+        assert!(outer.allocations >= 2);
+    }
+
+    #[cfg(not(feature = "memory"))]
+    #[test]
+    fn measures_nothing() {
+        assert_eq!(measure(|| vec![1, 2, 3]).1, None);
+    }
+}
@@ -0,0 +1,602 @@
+use crate::bench::format_secs;
+use crate::memory::{self, Usage};
+use aoc_common::parallel;
+use aoc_common::progress::{self, Cancelled, Progress};
+use aoc_common::{Answer, Day};
+use std::any::Any;
+use std::fmt;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How often running days are checked on.
+const POLL: Duration = Duration::from_millis(10);
+
+/// How often running days get to show their progress.
+const TICK: Duration = Duration::from_secs(1);
+
+/// How long a cancelled day gets to stop before it's given up on.
+const GRACE: Duration = Duration::from_millis(100);
+
+/// How running a day went.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Ok,
+    /// The input couldn't be read or parsed, or the solution panicked.
+    Failed(String),
+    /// The day ran out of time and was stopped.
+    TimedOut,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => f.pad("ok"),
+            Status::Failed(reason) => write!(f, "failed: {}", reason),
+            Status::TimedOut => f.pad("timed out"),
+        }
+    }
+}
+
+/// A solved part, and how long solving it took.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Answer,
+    pub time: Duration,
+    /// How much solving it used the heap, if that's being measured.
+    pub heap: Option<Usage>,
+}
+
+/// The outcome of running both parts of a day.
+#[derive(Debug, Clone)]
+pub struct DayRun {
+    pub day: u8,
+    /// How much parsing used the heap, if that's being measured.
+    pub parse_heap: Option<Usage>,
+    pub part1: Option<Solved>,
+    pub part2: Option<Solved>,
+    pub status: Status,
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+enum Failure {
+    Panicked(String),
+    Cancelled,
+}
+
+/// Calls `f` with its progress tracked, turning a panic into an error with the panic's message.
+fn catch<T, F: FnOnce() -> T>(progress: &Arc<Progress>, f: F) -> Result<T, Failure> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        progress::track(Arc::clone(progress), f)
+    }))
+    .map_err(|payload| {
+        if payload.is::<Cancelled>() {
+            Failure::Cancelled
+        } else {
+            Failure::Panicked(panic_message(payload))
+        }
+    })
+}
+
+/// Parses and solves the given parts of a day, catching any panic along the way.
+///
+/// A part that panics doesn't stop the other part from running, but once the progress is
+/// cancelled the day is over. A part with no puzzle is left unsolved without failing.
+pub fn run_day(
+    day: &Day,
+    input: &str,
+    path: &Path,
+    parts: &[u8],
+    progress: &Arc<Progress>,
+) -> DayRun {
+    let mut ret = DayRun {
+        day: day.day,
+        parse_heap: None,
+        part1: None,
+        part2: None,
+        status: Status::Ok,
+    };
+
+    let puzzle = match catch(progress, || memory::measure(|| day.parse(input))) {
+        Ok((Ok(puzzle), heap)) => {
+            ret.parse_heap = heap;
+            puzzle
+        }
+        Ok((Err(err), _)) => {
+            ret.status = Status::Failed(err.with_file(path).to_string());
+            return ret;
+        }
+        Err(Failure::Panicked(message)) => {
+            ret.status = Status::Failed(format!("panicked: {}", message));
+            return ret;
+        }
+        Err(Failure::Cancelled) => {
+            ret.status = Status::TimedOut;
+            return ret;
+        }
+    };
+
+    let mut failures = vec![];
+
+    for &part in parts {
+        let start = Instant::now();
+
+        match catch(progress, || memory::measure(|| puzzle.part(part))) {
+            Ok((None, _)) => failures.push(format!("there is no part {}", part)),
+            Ok((Some(Answer::NoPuzzle), _)) => {}
+            Ok((Some(answer), heap)) => {
+                let solved = Solved {
+                    answer,
+                    time: start.elapsed(),
+                    heap,
+                };
+
+                if part == 1 {
+                    ret.part1 = Some(solved);
+                } else {
+                    ret.part2 = Some(solved);
+                }
+            }
+            Err(Failure::Panicked(message)) => {
+                failures.push(format!("part {} panicked: {}", part, message))
+            }
+            Err(Failure::Cancelled) => {
+                ret.status = Status::TimedOut;
+                return ret;
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        ret.status = Status::Failed(failures.join(", "));
+    }
+
+    ret
+}
+
+fn unsolved(day: u8, status: Status) -> DayRun {
+    DayRun {
+        day,
+        parse_heap: None,
+        part1: None,
+        part2: None,
+        status,
+    }
+}
+
+fn run_file(day: &Day, path: &Path, progress: &Arc<Progress>) -> DayRun {
+    match fs::read_to_string(path) {
+        Ok(input) => run_day(day, &input, path, &[1, 2], progress),
+        Err(err) => unsolved(
+            day.day,
+            Status::Failed(format!("can't read {}: {}", path.display(), err)),
+        ),
+    }
+}
+
+struct Running {
+    day: u8,
+    started: Instant,
+    progress: Arc<Progress>,
+}
+
+/// Keeps track of the days that are running, see [`supervise`].
+#[derive(Default)]
+pub struct Supervisor {
+    running: Mutex<Vec<Running>>,
+}
+
+impl Supervisor {
+    /// Starts the clock on a day, returning the progress to run it with.
+    pub fn start(&self, day: u8) -> Arc<Progress> {
+        let progress = Arc::new(Progress::default());
+
+        self.running.lock().unwrap().push(Running {
+            day,
+            started: Instant::now(),
+            progress: Arc::clone(&progress),
+        });
+
+        progress
+    }
+
+    pub fn finish(&self, progress: &Arc<Progress>) {
+        self.running
+            .lock()
+            .unwrap()
+            .retain(|r| !Arc::ptr_eq(&r.progress, progress));
+    }
+
+    /// Runs a day with `run` on a thread of its own, and waits for it to finish.
+    ///
+    /// A day that times out but doesn't stop, because it never reports its progress, is left
+    /// running in the background and reported as timed out.
+    pub fn run<F>(&self, day: u8, run: F) -> DayRun
+    where
+        F: FnOnce(&Arc<Progress>) -> DayRun + Send + 'static,
+    {
+        let progress = self.start(day);
+        let (done, finished) = mpsc::channel();
+
+        let worker = {
+            let progress = Arc::clone(&progress);
+            thread::spawn(move || {
+                let _ = done.send(run(&progress));
+            })
+        };
+
+        let mut cancelled = None;
+
+        let ret = loop {
+            match finished.recv_timeout(POLL) {
+                Ok(run) => break run,
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    if progress.is_cancelled() {
+                        let since = *cancelled.get_or_insert_with(Instant::now);
+
+                        if since.elapsed() >= GRACE {
+                            break unsolved(day, Status::TimedOut);
+                        }
+                    }
+                }
+                // Only if `run` itself panicked
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    self.finish(&progress);
+                    match worker.join() {
+                        Ok(()) => unreachable!("the day finished without a result"),
+                        Err(payload) => panic::resume_unwind(payload),
+                    }
+                }
+            }
+        };
+
+        self.finish(&progress);
+        ret
+    }
+}
+
+/// Does `work` on another thread while watching over the days it runs: any day still running
+/// after `timeout` is cancelled, and every second `tick` is called with each running day, how long
+/// it's been running and its progress.
+pub fn supervise<T, W, F>(timeout: Option<Duration>, mut tick: F, work: W) -> T
+where
+    T: Send,
+    W: FnOnce(&Supervisor) -> T + Send,
+    F: FnMut(u8, Duration, &Progress),
+{
+    let supervisor = Supervisor::default();
+    let (done, finished) = mpsc::channel();
+
+    thread::scope(|s| {
+        let worker = s.spawn(|| {
+            let ret = work(&supervisor);
+            let _ = done.send(());
+            ret
+        });
+
+        let mut last_tick = Instant::now();
+
+        // The channel also disconnects if the work panics
+        while finished.recv_timeout(POLL) == Err(mpsc::RecvTimeoutError::Timeout) {
+            let ticking = last_tick.elapsed() >= TICK;
+            if ticking {
+                last_tick = Instant::now();
+            }
+
+            for running in supervisor.running.lock().unwrap().iter() {
+                let elapsed = running.started.elapsed();
+
+                if timeout.is_some_and(|timeout| elapsed >= timeout) {
+                    running.progress.cancel();
+                }
+
+                if ticking {
+                    tick(running.day, elapsed, &running.progress);
+                }
+            }
+        }
+
+        worker
+            .join()
+            .unwrap_or_else(|payload| panic::resume_unwind(payload))
+    })
+}
+
+/// Runs every day on its input, spread over `threads` threads, and returns the runs in the same
+/// order as the days were given.
+pub fn run_all(
+    supervisor: &Supervisor,
+    days: &[(&'static Day, PathBuf)],
+    threads: usize,
+) -> Vec<DayRun> {
+    parallel::map(days.iter(), threads, |&(day, ref path)| {
+        let path = path.clone();
+        supervisor.run(day.day, move |progress| run_file(day, &path, progress))
+    })
+}
+
+/// Lays out the runs as a table, one day per row.
+///
+/// When heap usage is measured, it's shown as the peak heap size and the number of allocations
+/// of parsing and each part.
+pub fn table(runs: &[DayRun]) -> String {
+    let heap = runs.iter().any(|r| r.parse_heap.is_some());
+
+    let mut header = vec!["Day", "Part 1", "Part 2", "Time 1", "Time 2"];
+    if heap {
+        header.extend(&["Heap parse", "Heap 1", "Heap 2"]);
+    }
+    header.push("Status");
+
+    let rows: Vec<Vec<String>> = runs
+        .iter()
+        .map(|run| {
+            let cell = |part: &Option<Solved>, f: &dyn Fn(&Solved) -> String| {
+                part.as_ref().map_or_else(|| "-".to_string(), f)
+            };
+            let answer = |p: &Solved| p.answer.to_string();
+            let time = |p: &Solved| format_secs(p.time.as_secs_f64());
+            let usage =
+                |usage: Option<Usage>| usage.map_or_else(|| "-".to_string(), |u| u.to_string());
+
+            let mut row = vec![
+                run.day.to_string(),
+                cell(&run.part1, &answer),
+                cell(&run.part2, &answer),
+                cell(&run.part1, &time),
+                cell(&run.part2, &time),
+            ];
+
+            if heap {
+                row.push(usage(run.parse_heap));
+                row.push(cell(&run.part1, &|p| usage(p.heap)));
+                row.push(cell(&run.part2, &|p| usage(p.heap)));
+            }
+
+            row.push(run.status.to_string());
+            row
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.chars().count()).collect();
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let line = |cells: &[String]| {
+        let cells: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| {
+                // Numbers line up on the right, but not the status at the end
+                if i + 1 < widths.len() {
+                    format!("{:>1$}", cell, width)
+                } else {
+                    cell.clone()
+                }
+            })
+            .collect();
+
+        cells.join(" | ")
+    };
+
+    let header: Vec<String> = header.into_iter().map(String::from).collect();
+    let mut ret = line(&header);
+    ret.push('\n');
+    ret.push_str(
+        &widths
+            .iter()
+            .map(|&w| "-".repeat(w))
+            .collect::<Vec<_>>()
+            .join("-|-"),
+    );
+    ret.push('\n');
+
+    for row in &rows {
+        ret.push_str(&line(row));
+        ret.push('\n');
+    }
+
+    ret
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use aoc_common::{ParseError, Solution};
+
+    /// Sums the numbers in part 1, and multiplies them in part 2 unless there are more than two.
+    struct Sums;
+
+    impl Solution for Sums {
+        type Input = Vec<u32>;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            aoc_common::parsers::lines(input, "a number")
+        }
+
+        fn part1(input: &Vec<u32>) -> Answer {
+            input.iter().sum::<u32>().into()
+        }
+
+        fn part2(input: &Vec<u32>) -> Answer {
+            if input.len() > 2 {
+                panic!("too many numbers");
+            }
+
+            input.iter().product::<u32>().into()
+        }
+    }
+
+    pub(crate) const SUMS: Day = Day::new::<Sums>(1);
+
+    struct Forever;
+
+    impl Solution for Forever {
+        type Input = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Answer {
+            for i in 0.. {
+                progress::report(i, None);
+            }
+
+            unreachable!()
+        }
+
+        fn part2(_: &()) -> Answer {
+            panic!("part 2 shouldn't run after part 1 timed out")
+        }
+    }
+
+    const FOREVER: Day = Day::new::<Forever>(2);
+
+    struct Stuck;
+
+    impl Solution for Stuck {
+        type Input = ();
+
+        fn parse(_: &str) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_: &()) -> Answer {
+            loop {
+                thread::sleep(POLL);
+            }
+        }
+
+        fn part2(_: &()) -> Answer {
+            panic!("part 2 shouldn't run after part 1 timed out")
+        }
+    }
+
+    const STUCK: Day = Day::new::<Stuck>(3);
+
+    fn run(input: &str, path: &str) -> DayRun {
+        run_day(&SUMS, input, Path::new(path), &[1, 2], &Arc::default())
+    }
+
+    #[test]
+    fn catches_panics() {
+        let run = run("1\n2\n3\n", "input.txt");
+
+        assert_eq!(run.part1.unwrap().answer, Answer::Number(6));
+        assert!(run.part2.is_none());
+        assert_eq!(
+            run.status,
+            Status::Failed("part 2 panicked: too many numbers".to_string())
+        );
+    }
+
+    #[test]
+    fn skips_parts_with_no_puzzle() {
+        let run = run_day(
+            &day25::DAY,
+            "5764801\n17807724\n",
+            Path::new("input.txt"),
+            &[1, 2],
+            &Arc::default(),
+        );
+
+        assert!(run.part1.is_some());
+        assert!(run.part2.is_none());
+        assert_eq!(run.status, Status::Ok);
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let run = run("1\nx\n", "input.txt");
+
+        assert!(run.part1.is_none());
+        assert_eq!(
+            run.status,
+            Status::Failed("input.txt:2:1: expected a number, found \"x\"".to_string())
+        );
+    }
+
+    #[test]
+    fn runs_in_order() {
+        let dir = crate::stub::temp_dir("runs_in_order");
+        let mut days = vec![];
+
+        for i in 0..8 {
+            let path = dir.join(format!("{}.txt", i));
+            fs::write(&path, format!("{}\n{}\n", i, i)).unwrap();
+            days.push((&SUMS, path));
+        }
+        days.push((&SUMS, dir.join("missing.txt")));
+
+        let runs = supervise(None, |_, _, _| {}, |s| run_all(s, &days, 3));
+        let answers: Vec<_> = runs[..8]
+            .iter()
+            .map(|r| r.part2.as_ref().unwrap().answer.clone())
+            .collect();
+
+        assert_eq!(
+            answers,
+            (0..8).map(|i| Answer::Number(i * i)).collect::<Vec<_>>()
+        );
+        assert!(matches!(runs[8].status, Status::Failed(_)));
+    }
+
+    #[test]
+    fn times_out() {
+        let dir = crate::stub::temp_dir("times_out");
+        let path = dir.join("input.txt");
+        fs::write(&path, "").unwrap();
+
+        let days = [(&FOREVER, path.clone()), (&SUMS, path)];
+        let timeout = Some(Duration::from_millis(50));
+        let runs = supervise(timeout, |_, _, _| {}, |s| run_all(s, &days, 2));
+
+        assert_eq!(runs[0].status, Status::TimedOut);
+        assert_eq!(runs[1].status, Status::Ok);
+    }
+
+    #[test]
+    fn leaves_days_that_never_report_behind() {
+        let dir = crate::stub::temp_dir("leaves_days_that_never_report_behind");
+        let path = dir.join("input.txt");
+        fs::write(&path, "").unwrap();
+
+        let days = [(&STUCK, path.clone()), (&SUMS, path)];
+        let timeout = Some(Duration::from_millis(50));
+        let runs = supervise(timeout, |_, _, _| {}, |s| run_all(s, &days, 2));
+
+        assert_eq!(runs[0].status, Status::TimedOut);
+        assert_eq!(runs[1].status, Status::Ok);
+    }
+
+    #[test]
+    fn lays_out_a_table() {
+        let runs = [run("12\n30\n", "a"), run("1\n2\n3\n", "b")];
+        let table = table(&runs);
+        let lines: Vec<_> = table.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+        assert!(lines[0].starts_with("Day | Part 1 | Part 2 | "));
+        assert!(lines[2].starts_with("  1 |     42 |    360 | "));
+        assert!(lines[2].ends_with(" | ok"));
+        assert!(lines[3].starts_with("  1 |      6 |      - | "));
+        assert!(lines[3].ends_with(" | failed: part 2 panicked: too many numbers"));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::days;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("shell.nix", include_str!("../templates/shell.nix.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+];
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{day}}", &format!("{:02}", day))
+        .replace("{{number}}", &day.to_string())
+}
+
+/// Adds `line` among the consecutive lines that `is_entry` picks out, keeping them sorted.
+///
+/// Returns `None` if there are no such lines to add to, or if `line` is already one of them.
+fn insert_sorted(text: &str, is_entry: impl Fn(&str) -> bool, line: &str) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let first = lines.iter().position(|l| is_entry(l))?;
+    let count = lines[first..].iter().take_while(|l| is_entry(l)).count();
+    let entries = &lines[first..first + count];
+
+    if entries.contains(&line) {
+        return None;
+    }
+
+    let at = first + entries.iter().take_while(|&&l| l < line).count();
+
+    let mut ret: Vec<&str> = lines[..at].to_vec();
+    ret.push(line);
+    ret.extend_from_slice(&lines[at..]);
+
+    Some(ret.join("\n") + "\n")
+}
+
+/// Reads the file at `path` and works out what it looks like with `line` added to it, see
+/// [`insert_sorted`].
+fn register(
+    path: &Path,
+    is_entry: impl Fn(&str) -> bool,
+    line: &str,
+) -> Result<String, Box<dyn Error>> {
+    let text =
+        fs::read_to_string(path).map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+
+    Ok(insert_sorted(&text, is_entry, line)
+        .ok_or_else(|| format!("couldn't add `{}` to {}", line.trim(), path.display()))?)
+}
+
+/// Creates the crate for a new day inside the workspace at `root`, and registers it in the runner.
+pub fn new_day(root: &Path, day: u8) -> Result<(), Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("there is no day {} in the calendar", day).into());
+    }
+
+    let dir = root.join(days::dir(day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    // Work out the registration before touching anything, so that nothing is left half done if
+    // it can't be
+    let manifest = root.join("aoc/Cargo.toml");
+    let manifest_text = register(
+        &manifest,
+        |l| l.starts_with("day") && l.contains("path = \"../day"),
+        &format!("day{0:02} = {{ path = \"../day{0:02}\" }}", day),
+    )?;
+    let registry = root.join("aoc/src/days.rs");
+    let registry_text = register(
+        &registry,
+        |l| l.trim_start().starts_with("day") && l.trim_end().ends_with("::DAY,"),
+        &format!("    day{:02}::DAY,", day),
+    )?;
+
+    fs::create_dir_all(dir.join("src"))?;
+    for (file, template) in TEMPLATES {
+        fs::write(dir.join(file), render(template, day))?;
+    }
+
+    fs::write(manifest, manifest_text)?;
+    fs::write(registry, registry_text)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub;
+
+    #[test]
+    fn renders_templates() {
+        let lib = render(TEMPLATES[2].1, 7);
+
+        assert!(lib.contains("pub struct Day07;"));
+        assert!(lib.contains("Day::new::<Day07>(7)"));
+        assert!(!lib.contains("{{"));
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let text = "a\n  day01::DAY,\n  day03::DAY,\nb\n";
+        let is_entry = |l: &str| l.trim_start().starts_with("day");
+
+        assert_eq!(
+            insert_sorted(text, is_entry, "  day02::DAY,").unwrap(),
+            "a\n  day01::DAY,\n  day02::DAY,\n  day03::DAY,\nb\n"
+        );
+        assert_eq!(
+            insert_sorted(text, is_entry, "  day04::DAY,").unwrap(),
+            "a\n  day01::DAY,\n  day03::DAY,\n  day04::DAY,\nb\n"
+        );
+        assert_eq!(insert_sorted(text, is_entry, "  day03::DAY,"), None);
+        assert_eq!(insert_sorted("a\nb\n", is_entry, "  day03::DAY,"), None);
+    }
+
+    #[test]
+    fn creates_a_day() {
+        let root = stub::temp_dir("creates_a_day");
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "[dependencies]\n\
+             aoc-common = { workspace = true }\n\
+             day01 = { path = \"../day01\" }\n\
+             day02 = { path = \"../day02\" }\n\
+             \n\
+             [dev-dependencies]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc/src/days.rs"),
+            "pub const DAYS: &[Day] = &[\n    day01::DAY,\n    day02::DAY,\n];\n",
+        )
+        .unwrap();
+
+        new_day(&root, 3).unwrap();
+
+        assert!(root.join("day03/Cargo.toml").exists());
+        assert!(root.join("day03/shell.nix").exists());
+        assert!(root.join("day03/src/lib.rs").exists());
+
+        let manifest = fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap();
+        assert!(manifest
+            .contains("day02 = { path = \"../day02\" }\nday03 = { path = \"../day03\" }\n\n"));
+
+        let registry = fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+        assert!(registry.contains("    day02::DAY,\n    day03::DAY,\n];"));
+
+        assert!(new_day(&root, 3).is_err());
+        assert!(new_day(&root, 26).is_err());
+    }
+
+    #[test]
+    fn leaves_nothing_behind() {
+        let root = stub::temp_dir("leaves_nothing_behind");
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+
+        // Neither file has anywhere to add the day, and the second one is missing altogether
+        assert!(new_day(&root, 3).is_err());
+        fs::write(
+            root.join("aoc/Cargo.toml"),
+            "day01 = { path = \"../day01\" }\n",
+        )
+        .unwrap();
+        assert!(new_day(&root, 3).is_err());
+
+        assert!(!root.join("day03").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("aoc/Cargo.toml")).unwrap(),
+            "day01 = { path = \"../day01\" }\n"
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! A stand-in for the Advent of Code website, for testing the client against.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+/// A request the stub server received.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    pub url: String,
+    pub cookie: Option<String>,
+    pub body: String,
+}
+
+/// A local HTTP server that answers with canned responses, in order, and records every request.
+pub struct Stub {
+    port: u16,
+    requests: Arc<Mutex<Vec<Request>>>,
+}
+
+impl Stub {
+    pub fn start(responses: Vec<(u16, String)>) -> Self {
+        let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+        let port = server.server_addr().to_ip().unwrap().port();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let recorded = Arc::clone(&requests);
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let mut request = match server.recv() {
+                    Ok(request) => request,
+                    Err(_) => return,
+                };
+
+                let mut content = String::new();
+                request.as_reader().read_to_string(&mut content).unwrap();
+
+                recorded.lock().unwrap().push(Request {
+                    method: request.method().to_string(),
+                    url: request.url().to_string(),
+                    cookie: request
+                        .headers()
+                        .iter()
+                        .find(|h| h.field.equiv("Cookie"))
+                        .map(|h| h.value.to_string()),
+                    body: content,
+                });
+
+                let response = tiny_http::Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+
+        Self { port, requests }
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port)
+    }
+
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// An empty directory to play around in, unique to the test.
+pub fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-test-{}-{}", std::process::id(), name));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,320 @@
+use crate::client::Client;
+use aoc_common::Answer;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the website made of a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    /// The part isn't open yet, or it has already been solved.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the website answers a submission with.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Right
+        } else if page.contains("answer too recently") {
+            Verdict::RateLimited
+        } else if page.contains("solving the right level") {
+            Verdict::WrongLevel
+        } else if page.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if page.contains("your answer is too low") {
+            Verdict::TooLow
+        } else if page.contains("not the right answer") {
+            Verdict::Wrong
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Whether the answer is known to be wrong, so it shouldn't be sent again.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Right => "right",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited => "rate limited",
+            Verdict::WrongLevel => "for a part that isn't open or is already solved",
+            Verdict::Unknown => "not understood",
+        })
+    }
+}
+
+/// The text of the main part of a page, without any of the markup.
+pub fn page_text(page: &str) -> String {
+    let article = match (page.find("<article"), page.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &page[start..end],
+        _ => page,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// One submitted answer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attempt {
+    /// When it was submitted, in seconds since the Unix epoch.
+    pub time: u64,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer ever submitted for a day, kept as one JSON object per line.
+#[derive(Debug)]
+pub struct Log {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Log {
+    /// Reads the log at `path`, or starts an empty one if there is no such file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => vec![],
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Explains why an answer shouldn't be submitted, if the log already shows it can't be right.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+
+        for attempt in self.attempts.iter().filter(|a| a.part == part) {
+            if attempt.verdict == Verdict::Right {
+                return Err(format!(
+                    "part {} was already solved with {}",
+                    part, attempt.answer
+                ));
+            }
+
+            if attempt.answer == answer && attempt.verdict.is_wrong() {
+                return Err(format!(
+                    "{} was already submitted for part {}, and it was {}",
+                    answer, part, attempt.verdict
+                ));
+            }
+
+            // A wrong guess that was too high or too low rules out more than just itself
+            let ruled_out = match (number, attempt.answer.parse::<i128>(), attempt.verdict) {
+                (Some(n), Ok(high), Verdict::TooHigh) => n >= high,
+                (Some(n), Ok(low), Verdict::TooLow) => n <= low,
+                _ => false,
+            };
+
+            if ruled_out {
+                return Err(format!(
+                    "{} was already found to be {} for part {}, so {} can't be right",
+                    attempt.answer, attempt.verdict, part, answer
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds an attempt to the log, saving it straight away.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), Box<dyn Error>> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+
+        writeln!(file, "{}", serde_json::to_string(&attempt)?)?;
+        self.attempts.push(attempt);
+
+        Ok(())
+    }
+}
+
+/// Submits an answer unless the log shows it's wrong, or it's for a part with no puzzle, and
+/// records what the website said.
+///
+/// Returns the verdict along with the text of the website's response.
+pub fn submit(
+    client: &Client,
+    log: &mut Log,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+) -> Result<(Verdict, String), Box<dyn Error>> {
+    if *answer == Answer::NoPuzzle {
+        return Err(format!("there's no puzzle to answer in day {} part {}", day, part).into());
+    }
+
+    let answer = answer.to_string();
+    log.check(part, &answer)?;
+
+    let page = client.answer(day, part, &answer)?;
+    let verdict = Verdict::parse(&page);
+
+    log.record(Attempt {
+        time: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        part,
+        answer,
+        verdict,
+    })?;
+
+    Ok((verdict, page_text(&page)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Config;
+    use crate::stub::{self, Stub};
+
+    const RIGHT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+                         closer to saving your vacation.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+                            high.  Please wait one minute before trying again.</p></article>\
+                            </main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to \
+                              wait after submitting an answer before trying again.  You have \
+                              35s left to wait.</p></article></main>";
+
+    fn client(stub: &Stub) -> Client {
+        Client::new(&Config {
+            session: Some("s3cr3t".to_string()),
+            base_url: Some(stub.url()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn reads_verdicts() {
+        assert_eq!(Verdict::parse(RIGHT), Verdict::Right);
+        assert_eq!(Verdict::parse(TOO_HIGH), Verdict::TooHigh);
+        assert_eq!(Verdict::parse(TOO_RECENT), Verdict::RateLimited);
+        assert_eq!(
+            Verdict::parse("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            Verdict::parse("That's not the right answer.  If you're stuck, ..."),
+            Verdict::Wrong
+        );
+        assert_eq!(
+            Verdict::parse("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert_eq!(Verdict::parse("<html></html>"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn strips_markup() {
+        assert_eq!(
+            page_text(TOO_RECENT),
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 35s left to wait."
+        );
+    }
+
+    #[test]
+    fn submits_and_logs() {
+        let stub = Stub::start(vec![(200, TOO_HIGH.to_string()), (200, RIGHT.to_string())]);
+        let dir = stub::temp_dir("submits_and_logs");
+        let path = dir.join("submissions.jsonl");
+        let client = client(&stub);
+        let mut log = Log::load(&path).unwrap();
+
+        let (verdict, _) = submit(&client, &mut log, 1, 1, &Answer::Number(1000)).unwrap();
+        assert_eq!(verdict, Verdict::TooHigh);
+
+        let (verdict, _) = submit(&client, &mut log, 1, 1, &Answer::Number(514)).unwrap();
+        assert_eq!(verdict, Verdict::Right);
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2020/day/1/answer");
+        assert_eq!(requests[0].body, "level=1&answer=1000");
+
+        let log = Log::load(&path).unwrap();
+        let verdicts: Vec<_> = log.attempts().iter().map(|a| a.verdict).collect();
+        assert_eq!(verdicts, vec![Verdict::TooHigh, Verdict::Right]);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let stub = Stub::start(vec![]);
+        let dir = stub::temp_dir("refuses_known_wrong_answers");
+        let mut log = Log::load(&dir.join("submissions.jsonl")).unwrap();
+
+        for (answer, verdict) in &[
+            ("7", Verdict::Wrong),
+            ("100", Verdict::TooHigh),
+            ("10", Verdict::TooLow),
+        ] {
+            log.record(Attempt {
+                time: 0,
+                part: 2,
+                answer: answer.to_string(),
+                verdict: *verdict,
+            })
+            .unwrap();
+        }
+
+        assert!(log.check(2, "7").is_err());
+        assert!(log.check(2, "100").is_err());
+        assert!(log.check(2, "150").is_err());
+        assert!(log.check(2, "10").is_err());
+        assert!(log.check(2, "3").is_err());
+        assert!(log.check(2, "50").is_ok());
+        assert!(log.check(1, "7").is_ok());
+
+        let result = submit(&client(&stub), &mut log, 1, 2, &Answer::Number(7));
+        assert!(result.is_err());
+        let result = submit(&client(&stub), &mut log, 25, 2, &Answer::NoPuzzle);
+        assert!(result.is_err());
+        assert!(stub.requests().is_empty());
+    }
+}
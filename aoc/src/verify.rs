@@ -0,0 +1,253 @@
+use crate::runner::{self, Status as RunStatus};
+use aoc_common::{Answer, Day};
+use serde::Deserialize;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// An answer as written in `answers.toml`, either a number or a string.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Number(n) => write!(f, "{}", n),
+            Expected::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// The known answers for a day, as kept in its `answers.toml`:
+///
+/// ```toml
+/// part1 = 514579
+/// part2 = 241861950
+/// ```
+///
+/// Either part may be left out if its answer isn't known yet.
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers {
+    pub part1: Option<Expected>,
+    pub part2: Option<Expected>,
+}
+
+impl Answers {
+    /// Reads the answers from a file, or returns no answers at all if there is no such file.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(toml::from_str(&contents)?),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Status::Pass => "pass",
+            Status::Fail => "FAIL",
+            Status::Missing => "missing",
+        })
+    }
+}
+
+/// How one part of a day compared against its known answer.
+#[derive(Debug)]
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, or why there isn't one.
+    pub answer: Result<Answer, String>,
+    pub expected: Option<Expected>,
+}
+
+impl Outcome {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.expected) {
+            (Err(_), _) => Status::Fail,
+            (Ok(_), None) => Status::Missing,
+            (Ok(answer), Some(expected)) if expected.to_string() == answer.to_string() => {
+                Status::Pass
+            }
+            (Ok(_), Some(_)) => Status::Fail,
+        }
+    }
+}
+
+/// Solves both parts of a day and checks them against the known answers.
+///
+/// A day that doesn't parse or that panics fails, with the reason instead of an answer. Parts with
+/// no puzzle are left out.
+pub fn verify(day: &Day, input: &str, path: &Path, answers: &Answers) -> Vec<Outcome> {
+    let run = runner::run_day(day, input, path, &[1, 2], &Arc::default());
+    let reason = match &run.status {
+        RunStatus::Ok => None,
+        RunStatus::Failed(reason) => Some(reason.clone()),
+        status => Some(status.to_string()),
+    };
+
+    vec![(1, run.part1), (2, run.part2)]
+        .into_iter()
+        // A day that ran fine but didn't solve a part had nothing to solve in it
+        .filter(|(_, solved)| solved.is_some() || reason.is_some())
+        .map(|(part, solved)| Outcome {
+            day: day.day,
+            part,
+            answer: solved
+                .map(|s| s.answer)
+                .ok_or_else(|| reason.clone().unwrap_or_default()),
+            expected: answers.part(part).cloned(),
+        })
+        .collect()
+}
+
+/// Fails if any part did, counting how many.
+pub fn check(outcomes: &[Outcome]) -> Result<(), String> {
+    let failed = outcomes
+        .iter()
+        .filter(|o| o.status() == Status::Fail)
+        .count();
+
+    if failed > 0 {
+        return Err(format!("{} answers didn't match", failed));
+    }
+
+    Ok(())
+}
+
+/// Checks a day against its input and known answers as kept in files. Files that can't be read
+/// make the day fail.
+pub fn verify_files(day: &Day, input: &Path, answers: &Path) -> Vec<Outcome> {
+    let checked = fs::read_to_string(input)
+        .map_err(|err| format!("can't read {}: {}", input.display(), err))
+        .and_then(|input| {
+            let answers = Answers::load(answers)
+                .map_err(|err| format!("can't read {}: {}", answers.display(), err))?;
+            Ok((input, answers))
+        });
+
+    match checked {
+        Ok((contents, answers)) => verify(day, &contents, input, &answers),
+        Err(reason) => (1..=2)
+            .map(|part| Outcome {
+                day: day.day,
+                part,
+                answer: Err(reason.clone()),
+                expected: None,
+            })
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::tests::SUMS;
+
+    fn outcome(answer: Result<Answer, String>, expected: Option<Expected>) -> Outcome {
+        Outcome {
+            day: 1,
+            part: 1,
+            answer,
+            expected,
+        }
+    }
+
+    #[test]
+    fn compares_answers() {
+        let number = || Ok(Answer::Number(42));
+        let text = || Ok(Answer::Text("abc".to_string()));
+
+        assert_eq!(
+            outcome(number(), Some(Expected::Number(42))).status(),
+            Status::Pass
+        );
+        assert_eq!(
+            outcome(number(), Some(Expected::Number(41))).status(),
+            Status::Fail
+        );
+        assert_eq!(
+            outcome(number(), Some(Expected::Text("42".to_string()))).status(),
+            Status::Pass
+        );
+        assert_eq!(
+            outcome(text(), Some(Expected::Text("abc".to_string()))).status(),
+            Status::Pass
+        );
+        assert_eq!(
+            outcome(text(), Some(Expected::Text("abd".to_string()))).status(),
+            Status::Fail
+        );
+        assert_eq!(outcome(text(), None).status(), Status::Missing);
+        assert_eq!(
+            outcome(Err("broken".to_string()), None).status(),
+            Status::Fail
+        );
+    }
+
+    #[test]
+    fn loads_missing_answers() {
+        let answers = Answers::load(Path::new("/nonexistent/answers.toml")).unwrap();
+        assert!(answers.part(1).is_none() && answers.part(2).is_none());
+    }
+
+    #[test]
+    fn checks_every_part() {
+        let answers = Answers {
+            part1: Some(Expected::Number(6)),
+            part2: None,
+        };
+        let statuses = |outcomes: &[Outcome]| -> Vec<Status> {
+            outcomes.iter().map(Outcome::status).collect()
+        };
+
+        let outcomes = verify(&SUMS, "1\n5\n", Path::new("input.txt"), &answers);
+        assert_eq!(statuses(&outcomes), [Status::Pass, Status::Missing]);
+        assert_eq!(check(&outcomes), Ok(()));
+
+        // Part 2 panics on this one, which is a failure even with no answer to compare against
+        let outcomes = verify(&SUMS, "1\n2\n3\n", Path::new("input.txt"), &answers);
+        assert_eq!(statuses(&outcomes), [Status::Pass, Status::Fail]);
+        assert_eq!(check(&outcomes), Err("1 answers didn't match".to_string()));
+    }
+
+    #[test]
+    fn leaves_out_parts_with_no_puzzle() {
+        let answers = Answers {
+            part1: Some(Expected::Number(14897079)),
+            part2: None,
+        };
+
+        let outcomes = verify(
+            &day25::DAY,
+            "5764801\n17807724\n",
+            Path::new("input.txt"),
+            &answers,
+        );
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].status(), Status::Pass);
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct ArgError(String);
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for ArgError {}
+
+/// Command line arguments of the form `command --name value --switch`.
+#[derive(Debug, Default)]
+pub struct Args {
+    command: Option<String>,
+    extra: Vec<String>,
+    options: HashMap<String, Option<String>>,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self::parse(env::args().skip(1))
+    }
+
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Self {
+        let mut ret = Self::default();
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                Some(option) => {
+                    if let Some((name, value)) = option.split_once('=') {
+                        ret.options
+                            .insert(name.to_string(), Some(value.to_string()));
+                    } else {
+                        let value = args.next_if(|next| !next.starts_with("--"));
+                        ret.options.insert(option.to_string(), value);
+                    }
+                }
+                None if ret.command.is_none() => ret.command = Some(arg),
+                None => ret.extra.push(arg),
+            }
+        }
+
+        ret
+    }
+
+    /// Fails on any option that isn't one of `values` or `switches`, on a value option given
+    /// without its value, on a switch given one, and on more than one positional argument.
+    pub fn check(&self, values: &[&str], switches: &[&str]) -> Result<(), ArgError> {
+        if let Some(arg) = self.extra.first() {
+            return Err(ArgError(format!("unexpected argument {}", arg)));
+        }
+
+        let mut names: Vec<&String> = self.options.keys().collect();
+        names.sort();
+
+        for name in names {
+            let value = &self.options[name];
+
+            if values.contains(&name.as_str()) {
+                if value.is_none() {
+                    return Err(ArgError(format!("missing value for --{}", name)));
+                }
+            } else if switches.contains(&name.as_str()) {
+                if let Some(value) = value {
+                    return Err(ArgError(format!(
+                        "--{} doesn't take a value, got {}",
+                        name, value
+                    )));
+                }
+            } else {
+                return Err(ArgError(format!("unexpected argument --{}", name)));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn command(&self) -> Option<&str> {
+        self.command.as_deref()
+    }
+
+    /// Whether a switch was given, with or without a value.
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.options.get(name).and_then(|value| value.as_deref())
+    }
+
+    pub fn value<T: FromStr>(&self, name: &str) -> Result<Option<T>, ArgError> {
+        match self.get(name) {
+            None => Ok(None),
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| ArgError(format!("invalid value for --{}: {}", name, value))),
+        }
+    }
+
+    pub fn required<T: FromStr>(&self, name: &str) -> Result<T, ArgError> {
+        self.value(name)?
+            .ok_or_else(|| ArgError(format!("missing required option --{}", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(args: &str) -> Result<(), String> {
+        Args::parse(args.split_whitespace().map(str::to_string))
+            .check(&["day", "part"], &["all"])
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn accepts_known_options() {
+        assert!(check("run --day 1 --part=2").is_ok());
+        assert!(check("run --all").is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_options() {
+        assert_eq!(
+            check("run --day 1 --prat 2"),
+            Err("unexpected argument --prat".into())
+        );
+        assert_eq!(
+            check("run --day 1 --part"),
+            Err("missing value for --part".into())
+        );
+        assert_eq!(
+            check("run --day --all"),
+            Err("missing value for --day".into())
+        );
+        assert_eq!(
+            check("run --all 3"),
+            Err("--all doesn't take a value, got 3".into())
+        );
+        assert_eq!(check("run --day 1 2"), Err("unexpected argument 2".into()));
+    }
+}
@@ -0,0 +1,105 @@
+use std::error::Error;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// A problem with a puzzle input, pointing at where in the input it is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The file the input came from, if it's known.
+    pub file: Option<PathBuf>,
+    /// The line of the problem, counting from 1.
+    pub line: usize,
+    /// The column of the problem, in characters and counting from 1.
+    pub column: usize,
+    /// The offending text, from the problem to the end of its line.
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Builds an error for a problem at the start of `at`, which must be a slice of `source`, the
+    /// whole of the input. If it isn't, the problem is placed at the end of the input.
+    pub fn at<M: Into<String>>(source: &str, at: &str, message: M) -> Self {
+        let start = source.as_ptr() as usize;
+        let offset = match (at.as_ptr() as usize).checked_sub(start) {
+            Some(offset) if offset <= source.len() => offset,
+            _ => source.len(),
+        };
+
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let rest = &source[offset..];
+        let text = rest.lines().next().unwrap_or("");
+
+        Self {
+            file: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Records which file the input came from.
+    pub fn with_file<P: AsRef<Path>>(mut self, file: P) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:", file.display())?,
+            None => write!(f, "<input>:")?,
+        }
+
+        write!(f, "{}:{}: {}, ", self.line, self.column, self.message)?;
+
+        if self.text.is_empty() {
+            write!(f, "found the end of the line")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_the_problem() {
+        let source = "nop +0\nacc +1\njmp x4\n";
+        let line = source.lines().nth(2).unwrap();
+        let err = ParseError::at(source, &line[4..], "expected a number");
+
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.text, "x4");
+        assert_eq!(
+            err.with_file("day08/input.txt").to_string(),
+            "day08/input.txt:3:5: expected a number, found \"x4\""
+        );
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        let source = "µµx";
+        let err = ParseError::at(source, &source[4..], "bad");
+
+        assert_eq!(err.column, 3);
+    }
+
+    #[test]
+    fn falls_back_to_the_end() {
+        let err = ParseError::at("12\n34", "elsewhere", "expected more");
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "<input>:2:3: expected more, found the end of the line"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use crate::error::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// The four orthogonal directions, as `(dx, dy)` with y growing downwards.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The four orthogonal directions and the four diagonals.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+///
+/// Positions are `(x, y)` pairs, with `(0, 0)` in the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid by calling `cell` for every position, row by row.
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut cells = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(cell(x, y));
+            }
+        }
+
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, turning every character into a cell with `cell`.
+    ///
+    /// Fails if `cell` returns `None` for a character, if the lines aren't all the same length, or if
+    /// there are no cells at all.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let mut len = 0;
+
+            for (i, c) in line.char_indices() {
+                match cell(c) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(ParseError::at(input, &line[i..], "unexpected character")),
+                }
+
+                len += 1;
+            }
+
+            match width {
+                None => width = Some(len),
+                Some(expected) if expected != len => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected a line of {} characters", expected),
+                    ))
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::at(input, input, "expected a row of cells"));
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[x + y * self.width])
+        } else {
+            None
+        }
+    }
+
+    /// Gets a cell as if the grid repeated forever in every direction, so the grid can't be empty.
+    pub fn get_wrapping(&self, x: usize, y: usize) -> &T {
+        &self.cells[x % self.width + (y % self.height) * self.width]
+    }
+
+    /// Moves from a position by `(dx, dy)`, if that stays inside the grid.
+    pub fn step(&self, (x, y): (usize, usize), (dx, dy): (isize, isize)) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+
+        Some((x, y))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let width = self.width;
+
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    fn around<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        directions: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        directions
+            .iter()
+            .filter_map(move |&dir| self.step((x, y), dir))
+            .map(move |(x, y)| &self[(x, y)])
+    }
+
+    /// The orthogonal neighbours of a position that are inside the grid.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = &T> + '_ {
+        self.around(x, y, &DIRECTIONS4)
+    }
+
+    /// The orthogonal and diagonal neighbours of a position that are inside the grid.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = &T> + '_ {
+        self.around(x, y, &DIRECTIONS8)
+    }
+
+    /// Every cell from a position in a straight line towards `direction`, not counting the
+    /// starting cell, until the edge of the grid.
+    pub fn ray(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = &T> + '_ {
+        let mut pos = (x, y);
+
+        std::iter::from_fn(move || {
+            pos = self.step(pos, direction)?;
+            Some(&self[pos])
+        })
+    }
+
+    /// The first cell seen from a position when looking towards `direction`, skipping over any
+    /// cells that can be seen through.
+    pub fn first_seen<F>(
+        &self,
+        x: usize,
+        y: usize,
+        direction: (isize, isize),
+        mut see_through: F,
+    ) -> Option<&T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.ray(x, y, direction).find(|cell| !see_through(cell))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        self.get(x, y).expect("position outside of the grid")
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        self.get_mut(x, y).expect("position outside of the grid")
+    }
+}
+
+/// Draws the grid one row per line, every cell with its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 0)], 1);
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn rejects_bad_input() {
+        let err = Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "x");
+
+        let err = Grid::parse("12\n3", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected a line of 2 characters");
+
+        for input in &["", "\n\n"] {
+            let err = Grid::parse(input, |c| c.to_digit(10)).unwrap_err();
+            assert_eq!(
+                (err.line, err.message.as_str()),
+                (1, "expected a row of cells")
+            );
+        }
+    }
+
+    #[test]
+    fn wraps() {
+        let grid = digits("12\n34");
+
+        assert_eq!(*grid.get_wrapping(2, 0), 1);
+        assert_eq!(*grid.get_wrapping(5, 3), 4);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = digits("123\n456\n789");
+
+        let mut n4: Vec<_> = grid.neighbours4(0, 0).copied().collect();
+        n4.sort_unstable();
+        assert_eq!(n4, vec![2, 4]);
+
+        assert_eq!(grid.neighbours8(1, 1).sum::<u32>(), 45 - 5);
+        assert_eq!(grid.neighbours8(2, 2).count(), 3);
+    }
+
+    #[test]
+    fn casts_rays() {
+        let grid = digits("1000\n0000\n0002");
+
+        assert_eq!(grid.ray(0, 0, (1, 0)).count(), 3);
+        assert_eq!(grid.first_seen(0, 0, (1, 1), |&c| c == 0), None);
+        assert_eq!(grid.first_seen(3, 0, (0, 1), |&c| c == 0), Some(&2));
+        assert_eq!(grid.first_seen(3, 2, (-1, -1), |&c| c == 0), None);
+        assert_eq!(grid.first_seen(1, 0, (-1, 0), |&c| c == 0), Some(&1));
+    }
+}
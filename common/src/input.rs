@@ -0,0 +1,25 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+
+/// Reads a whole puzzle input into a string.
+///
+/// A path of `-`, or no path at all, reads from stdin instead.
+pub fn read(path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) if path != Path::new("-") => fs::read_to_string(path),
+        _ => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+    }
+}
+
+/// The name to show in messages for the input that [`read`] reads from `path`.
+pub fn name(path: Option<&Path>) -> &Path {
+    match path {
+        Some(path) if path != Path::new("-") => path,
+        _ => Path::new("<stdin>"),
+    }
+}
@@ -0,0 +1,14 @@
+//! Code shared between every day of Advent of Code 2020.
+
+pub mod args;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parsers;
+pub mod progress;
+pub mod solution;
+
+pub use error::ParseError;
+pub use grid::Grid;
+pub use solution::{Answer, Day, Puzzle, Solution};
@@ -0,0 +1,111 @@
+//! Mapping over jobs on a pool of threads, keeping the results in order.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+fn run<I, T, F, S>(items: I, threads: usize, f: F, stop: S) -> Vec<T>
+where
+    I: Iterator + Send,
+    T: Send,
+    F: Fn(I::Item) -> T + Sync,
+    S: Fn(&T) -> bool + Sync,
+{
+    let next = Mutex::new(items.enumerate());
+    let results = Mutex::new(vec![]);
+    let stopped = AtomicBool::new(false);
+
+    thread::scope(|s| {
+        for _ in 0..threads.max(1) {
+            s.spawn(|| loop {
+                if stopped.load(Ordering::Relaxed) {
+                    break;
+                }
+
+                let job = next.lock().unwrap().next();
+                let (i, item) = match job {
+                    Some(job) => job,
+                    None => break,
+                };
+
+                let result = f(item);
+                stopped.fetch_or(stop(&result), Ordering::Relaxed);
+                results.lock().unwrap().push((i, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|&(i, _)| i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Calls `f` on every item, spread over `threads` threads, and returns the results in the same
+/// order as the items.
+///
+/// The items are taken one at a time by whichever thread is free, so whatever work the iterator
+/// does to come up with the next one happens alongside `f` working on the ones before it.
+pub fn map<I, T, F>(items: I, threads: usize, f: F) -> Vec<T>
+where
+    I: Iterator + Send,
+    T: Send,
+    F: Fn(I::Item) -> T + Sync,
+{
+    run(items, threads, f, |_| false)
+}
+
+/// Like [`map`], but no more items are taken once `f` fails on one.
+///
+/// The results end at the first error, in the order of the items, and every item before it has
+/// its result, since those were all taken before it was.
+pub fn try_map<I, T, E, F>(items: I, threads: usize, f: F) -> Vec<Result<T, E>>
+where
+    I: Iterator + Send,
+    T: Send,
+    E: Send,
+    F: Fn(I::Item) -> Result<T, E> + Sync,
+{
+    let mut results = run(items, threads, f, Result::is_err);
+
+    if let Some(i) = results.iter().position(Result::is_err) {
+        results.truncate(i + 1);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_order() {
+        for threads in 0..=4 {
+            assert_eq!(
+                map(1..=100, threads, |n| n * n),
+                (1..=100).map(|n| n * n).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn stops_at_the_first_error() {
+        for threads in 1..=4 {
+            let results = try_map(
+                0..1000,
+                threads,
+                |n| {
+                    if n % 100 == 42 {
+                        Err(n)
+                    } else {
+                        Ok(n)
+                    }
+                },
+            );
+
+            assert_eq!(results.len(), 43);
+            assert!(results[..42].iter().enumerate().all(|(i, r)| *r == Ok(i)));
+            assert_eq!(results[42], Err(42));
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::error::ParseError;
+use nom::{character::complete::digit1, combinator::map_res, IResult};
+use std::str::FromStr;
+
+/// Parses an unsigned decimal number into any type that can be parsed from a string.
+pub fn number<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, |a: &str| a.parse())(input)
+}
+
+/// Runs a parser over `input`, which is either `source`, the whole of the puzzle input, or a slice
+/// of it such as a single line. Anything but whitespace left over by the parser is an error.
+///
+/// `what` describes what's being parsed, as in "expected {what}".
+pub fn parse_all<'a, T, P>(
+    source: &'a str,
+    input: &'a str,
+    what: &str,
+    mut parser: P,
+) -> Result<T, ParseError>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, T>,
+{
+    match parser(input) {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(ParseError::at(
+            source,
+            rest.trim_start(),
+            format!("unexpected text after {}", what),
+        )),
+        Err(nom::Err::Error(e)) | Err(nom::Err::Failure(e)) => Err(ParseError::at(
+            source,
+            e.input,
+            format!("expected {}", what),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(ParseError::at(
+            source,
+            &input[input.len()..],
+            format!("expected {}", what),
+        )),
+    }
+}
+
+/// Parses every line of the input on its own with `FromStr`.
+pub fn lines<T: FromStr>(source: &str, what: &str) -> Result<Vec<T>, ParseError> {
+    source
+        .lines()
+        .map(|line| {
+            line.parse()
+                .map_err(|_| ParseError::at(source, line, format!("expected {}", what)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+    use nom::sequence::preceded;
+
+    #[test]
+    fn parses_everything() {
+        let source = "x=1\nx=2";
+        let line = source.lines().nth(1).unwrap();
+
+        assert_eq!(
+            parse_all(source, line, "x", preceded(tag("x="), number::<u32>)),
+            Ok(2)
+        );
+
+        let err = parse_all(source, line, "x", tag("x")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.text, "=2");
+        assert_eq!(err.message, "unexpected text after x");
+
+        let err = parse_all(source, line, "y", tag("y")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected y");
+    }
+
+    #[test]
+    fn parses_lines() {
+        assert_eq!(lines::<u32>("1\n2\n3\n", "a number"), Ok(vec![1, 2, 3]));
+
+        let err = lines::<u32>("1\n2x\n3\n", "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.text, "2x");
+    }
+}
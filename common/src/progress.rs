@@ -0,0 +1,122 @@
+//! Progress reporting for solvers that take a while.
+//!
+//! A solver calls [`report`] as it goes with how far along it is, such as the current iteration
+//! or generation. When whoever runs the solver keeps track of it with [`track`], that shows up as
+//! the solver's progress, and it's also how a solver that ran out of time gets stopped: once the
+//! progress is cancelled, the next `report` unwinds with [`Cancelled`]. Otherwise `report` does
+//! nothing.
+//!
+//! A solver that never reports can't be stopped early, so the runner gives up waiting for it
+//! instead.
+
+use std::cell::RefCell;
+use std::panic;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// How far along a solver is, shared between the solver and whoever is running it.
+#[derive(Debug, Default)]
+pub struct Progress {
+    done: AtomicU64,
+    // 0 if the total isn't known
+    total: AtomicU64,
+    cancelled: AtomicBool,
+}
+
+impl Progress {
+    pub fn done(&self) -> u64 {
+        self.done.load(Ordering::Relaxed)
+    }
+
+    pub fn total(&self) -> Option<u64> {
+        Some(self.total.load(Ordering::Relaxed)).filter(|&t| t > 0)
+    }
+
+    /// Asks the solver to stop the next time it reports.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+/// What a cancelled solver unwinds with, see [`report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<Progress>>> = const { RefCell::new(None) };
+}
+
+/// Reports that `done` out of `total` steps are done, if the total is known.
+///
+/// This is cheap, but in a very hot loop it's still best to only call it every few thousand
+/// iterations.
+///
+/// # Panics
+///
+/// Unwinds with a [`Cancelled`] payload if the progress being tracked was cancelled. This doesn't
+/// go through the panic hook, so nothing is printed.
+pub fn report(done: u64, total: Option<u64>) {
+    let cancelled = CURRENT.with(|current| match &*current.borrow() {
+        Some(progress) => {
+            progress.done.store(done, Ordering::Relaxed);
+            progress.total.store(total.unwrap_or(0), Ordering::Relaxed);
+            progress.is_cancelled()
+        }
+        None => false,
+    });
+
+    if cancelled {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
+
+/// Runs `f` with everything it reports going to `progress`.
+pub fn track<T, F: FnOnce() -> T>(progress: Arc<Progress>, f: F) -> T {
+    // Put back whatever was tracked before, even when `f` unwinds
+    struct Restore(Option<Arc<Progress>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT.with(|current| current.borrow_mut().replace(progress)));
+    f()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_progress() {
+        let progress = Arc::new(Progress::default());
+
+        // Nothing is tracking this one, so it goes nowhere
+        report(1, None);
+
+        track(Arc::clone(&progress), || report(5, Some(10)));
+        assert_eq!((progress.done(), progress.total()), (5, Some(10)));
+
+        report(7, None);
+        assert_eq!(progress.done(), 5);
+    }
+
+    #[test]
+    fn stops_when_cancelled() {
+        let progress = Arc::new(Progress::default());
+        progress.cancel();
+
+        let result = panic::catch_unwind(|| track(Arc::clone(&progress), || report(1, None)));
+        assert!(result.unwrap_err().is::<Cancelled>());
+
+        // The tracking ends with the unwinding
+        report(2, None);
+        assert_eq!(progress.done(), 1);
+    }
+}
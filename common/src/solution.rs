@@ -0,0 +1,117 @@
+use crate::error::ParseError;
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    /// There's no puzzle in this part, like part 2 of the last day, so there's nothing to check
+    /// or submit.
+    NoPuzzle,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::NoPuzzle => f.pad("-"),
+        }
+    }
+}
+
+macro_rules! number_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+number_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A solution to a day's puzzle.
+///
+/// The input is parsed once, and both parts are solved from the parsed input. Parsing fails with a
+/// [`ParseError`] pointing at whatever it didn't understand.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+}
+
+/// A parsed puzzle input, with the type of the input hidden away.
+pub trait Puzzle {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+
+    /// Solves the given part, returning `None` if there is no such part.
+    fn part(&self, part: u8) -> Option<Answer> {
+        match part {
+            1 => Some(self.part1()),
+            2 => Some(self.part2()),
+            _ => None,
+        }
+    }
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> Puzzle for Parsed<S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Puzzle>, ParseError> {
+    Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+}
+
+/// A day of the calendar, as registered by each day's crate.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    parse: fn(&str) -> Result<Box<dyn Puzzle>, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution + 'static>(day: u8) -> Self {
+        Self {
+            day,
+            parse: parse::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Puzzle>, ParseError> {
+        (self.parse)(input)
+    }
+}
+
+impl fmt::Debug for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Day").field("day", &self.day).finish()
+    }
+}
@@ -0,0 +1,296 @@
+//! Finding `k` entries of a list that add up to a target.
+//!
+//! Entries are picked by index, so the same entry is never used twice, though two entries with
+//! the same value can both be picked. Indices are returned in increasing order.
+
+use std::collections::HashMap;
+
+/// Finds `k` entries that add up to `target`, returning their indices.
+///
+/// Pairs are found by hashing and triples with two pointers over the sorted entries. Anything
+/// larger meets in the middle: every combination of half the entries is hashed by its sum, and
+/// looked up from the combinations of the other half.
+pub fn find(nums: &[i64], k: usize, target: i64) -> Option<Vec<usize>> {
+    match k {
+        0 if target == 0 => Some(vec![]),
+        0 => None,
+        1 => nums.iter().position(|&n| n == target).map(|i| vec![i]),
+        2 => two_sum(nums, target),
+        3 => three_sum(nums, target),
+        _ => meet_in_the_middle(nums, k, target),
+    }
+}
+
+fn two_sum(nums: &[i64], target: i64) -> Option<Vec<usize>> {
+    let mut seen = HashMap::new();
+
+    for (j, &n) in nums.iter().enumerate() {
+        if let Some(&i) = seen.get(&(target - n)) {
+            return Some(vec![i, j]);
+        }
+
+        seen.entry(n).or_insert(j);
+    }
+
+    None
+}
+
+fn three_sum(nums: &[i64], target: i64) -> Option<Vec<usize>> {
+    let mut order: Vec<usize> = (0..nums.len()).collect();
+    order.sort_by_key(|&i| nums[i]);
+
+    for a in 0..order.len() {
+        let (mut lo, mut hi) = (a + 1, order.len().saturating_sub(1));
+
+        while lo < hi {
+            let sum = nums[order[a]] + nums[order[lo]] + nums[order[hi]];
+
+            if sum == target {
+                let mut ret = vec![order[a], order[lo], order[hi]];
+                ret.sort_unstable();
+                return Some(ret);
+            } else if sum < target {
+                lo += 1;
+            } else {
+                hi -= 1;
+            }
+        }
+    }
+
+    None
+}
+
+/// Calls `f` with every combination of `k` indices below `n`, each in increasing order.
+fn combinations<F: FnMut(&[usize])>(n: usize, k: usize, mut f: F) {
+    if k > n {
+        return;
+    }
+
+    let mut indices: Vec<usize> = (0..k).collect();
+
+    loop {
+        f(&indices);
+
+        // Move the last index that can still move, and reset every one after it
+        let i = match (0..k).rev().find(|&i| indices[i] < n - k + i) {
+            Some(i) => i,
+            None => return,
+        };
+
+        indices[i] += 1;
+        for j in i + 1..k {
+            indices[j] = indices[j - 1] + 1;
+        }
+    }
+}
+
+fn meet_in_the_middle(nums: &[i64], k: usize, target: i64) -> Option<Vec<usize>> {
+    let half = k / 2;
+
+    // The indices of a solution split into its lowest `half` and the rest, so for every sum
+    // only the combination that ends the earliest needs keeping
+    let mut lows: HashMap<i64, Vec<usize>> = HashMap::new();
+    combinations(nums.len(), half, |low| {
+        let sum = low.iter().map(|&i| nums[i]).sum();
+        let best = lows.entry(sum).or_insert_with(|| low.to_vec());
+
+        if low.last() < best.last() {
+            *best = low.to_vec();
+        }
+    });
+
+    let mut ret = None;
+    combinations(nums.len(), k - half, |high| {
+        if ret.is_some() {
+            return;
+        }
+
+        let sum: i64 = high.iter().map(|&i| nums[i]).sum();
+        if let Some(low) = lows.get(&(target - sum)) {
+            if low.last() < high.first() {
+                ret = Some(low.iter().chain(high).copied().collect());
+            }
+        }
+    });
+
+    ret
+}
+
+/// Finds the `k` entries whose sum is nearest to `target`, returning their indices along with how
+/// far off the sum is, as the sum minus the target. Ties go to the sum below the target.
+///
+/// Every combination of all but two entries is tried in sorted order, with two pointers for the
+/// last two, which is as fast as it gets for pairs and triples but slows down quickly after that.
+pub fn closest(nums: &[i64], k: usize, target: i64) -> Option<(Vec<usize>, i64)> {
+    if k > nums.len() {
+        return None;
+    }
+
+    let mut order: Vec<usize> = (0..nums.len()).collect();
+    order.sort_by_key(|&i| nums[i]);
+
+    let mut best: Option<(Vec<usize>, i64)> = None;
+    let mut consider = |picked: Vec<usize>, diff: i64| {
+        let better = match &best {
+            None => true,
+            Some((_, best)) => (diff.abs(), diff) < (best.abs(), *best),
+        };
+
+        if better {
+            best = Some((picked, diff));
+        }
+    };
+
+    if k < 2 {
+        combinations(order.len(), k, |picked| {
+            let sum: i64 = picked.iter().map(|&p| nums[order[p]]).sum();
+            consider(picked.iter().map(|&p| order[p]).collect(), sum - target);
+        });
+    } else {
+        combinations(order.len() - 2, k - 2, |fixed| {
+            let sum: i64 = fixed.iter().map(|&p| nums[order[p]]).sum();
+            let (mut lo, mut hi) = (fixed.last().map_or(0, |&p| p + 1), order.len() - 1);
+
+            while lo < hi {
+                let diff = sum + nums[order[lo]] + nums[order[hi]] - target;
+                let picked = fixed.iter().chain(&[lo, hi]).map(|&p| order[p]).collect();
+                consider(picked, diff);
+
+                match diff {
+                    0 => return,
+                    d if d < 0 => lo += 1,
+                    _ => hi -= 1,
+                }
+            }
+        });
+    }
+
+    best.map(|(mut picked, diff)| {
+        picked.sort_unstable();
+        (picked, diff)
+    })
+}
+
+/// Finds every set of `k` entries that add up to `target`, in order.
+///
+/// Entries that share a value still make different sets, so `[1010, 1010, 1010]` has three pairs
+/// that add up to 2020.
+pub fn all(nums: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    if k == 0 {
+        return if target == 0 { vec![vec![]] } else { vec![] };
+    }
+
+    let half = k / 2;
+
+    // As in `meet_in_the_middle`, but every set splits into its lowest `half` and the rest in
+    // exactly one way, so keeping every combination finds every set exactly once
+    let mut lows: HashMap<i64, Vec<Vec<usize>>> = HashMap::new();
+    combinations(nums.len(), half, |low| {
+        let sum = low.iter().map(|&i| nums[i]).sum();
+        lows.entry(sum).or_default().push(low.to_vec());
+    });
+
+    let mut ret = vec![];
+    combinations(nums.len(), k - half, |high| {
+        let sum: i64 = high.iter().map(|&i| nums[i]).sum();
+
+        for low in lows.get(&(target - sum)).into_iter().flatten() {
+            if low.last() < high.first() {
+                ret.push(low.iter().chain(high).copied().collect());
+            }
+        }
+    });
+
+    ret.sort_unstable();
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NUMS: [i64; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    fn check(nums: &[i64], k: usize, target: i64) {
+        let indices = find(nums, k, target).unwrap();
+
+        assert_eq!(indices.len(), k);
+        assert!(indices.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(indices.iter().map(|&i| nums[i]).sum::<i64>(), target);
+    }
+
+    #[test]
+    fn finds_the_examples() {
+        assert_eq!(find(&NUMS, 2, 2020), Some(vec![0, 3]));
+        assert_eq!(find(&NUMS, 3, 2020), Some(vec![1, 2, 4]));
+    }
+
+    #[test]
+    fn finds_any_k() {
+        let nums: Vec<i64> = (1..=20).map(|n| n * n - 50).collect();
+
+        let picks = [1, 4, 7, 9, 12, 19];
+
+        for k in 1..=6 {
+            let target = picks[..k].iter().map(|&i| nums[i]).sum();
+            check(&nums, k, target);
+        }
+    }
+
+    #[test]
+    fn uses_every_entry_once() {
+        assert_eq!(find(&[1010], 2, 2020), None);
+        assert_eq!(find(&[1010, 5, 1010], 2, 2020), Some(vec![0, 2]));
+        assert_eq!(find(&[500, 500, 500], 4, 2000), None);
+        assert_eq!(find(&[500, 500, 500, 500], 4, 2000), Some(vec![0, 1, 2, 3]));
+    }
+
+    #[test]
+    fn finds_nothing() {
+        assert_eq!(find(&NUMS, 2, 1), None);
+        assert_eq!(find(&NUMS, 3, 1), None);
+        assert_eq!(find(&NUMS, 4, 1), None);
+        assert_eq!(find(&NUMS, 7, 2020), None);
+        assert_eq!(find(&NUMS, 0, 0), Some(vec![]));
+    }
+
+    #[test]
+    fn finds_the_closest() {
+        assert_eq!(closest(&NUMS, 2, 2020), Some((vec![0, 3], 0)));
+        assert_eq!(closest(&NUMS, 2, 2030), Some((vec![0, 3], -10)));
+        assert_eq!(closest(&NUMS, 3, 2023), Some((vec![1, 2, 4], -3)));
+        assert_eq!(closest(&NUMS, 1, 300), Some((vec![3], -1)));
+        assert_eq!(closest(&NUMS, 6, 0), Some((vec![0, 1, 2, 3, 4, 5], 5496)));
+        assert_eq!(closest(&NUMS, 7, 0), None);
+
+        // 1721 + 979 + 366 + 299 is 3365, but there might be other sets just as close
+        let (picked, diff) = closest(&NUMS, 4, 3364).unwrap();
+        assert_eq!(picked.iter().map(|&i| NUMS[i]).sum::<i64>() - 3364, diff);
+        assert_eq!(diff.abs(), 1);
+    }
+
+    #[test]
+    fn finds_all() {
+        assert_eq!(all(&NUMS, 2, 2020), vec![vec![0, 3]]);
+        assert_eq!(
+            all(&[1010, 1010, 1010], 2, 2020),
+            vec![vec![0, 1], vec![0, 2], vec![1, 2]]
+        );
+        assert_eq!(all(&[1, 2, 3, 4], 2, 5), vec![vec![0, 3], vec![1, 2]]);
+        assert_eq!(all(&[1, 2, 3, 4], 4, 10), vec![vec![0, 1, 2, 3]]);
+        assert_eq!(all(&[1, 2, 3, 4], 1, 5), Vec::<Vec<usize>>::new());
+
+        let nums: Vec<i64> = (1..=12).collect();
+        for k in 1..=5 {
+            let found = all(&nums, k, 20);
+            let mut brute = vec![];
+            combinations(nums.len(), k, |c| {
+                if c.iter().map(|&i| nums[i]).sum::<i64>() == 20 {
+                    brute.push(c.to_vec());
+                }
+            });
+
+            assert_eq!(found, brute);
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::{parsers, Answer, Day, ParseError, Solution};
+use std::vec::Vec;
+
+pub mod ksum;
+
+const TARGET: i64 = 2020;
+
+/// Multiplies together the `k` entries that sum to 2020.
+fn product_of(nums: &[i64], k: usize) -> i64 {
+    match ksum::find(nums, k, TARGET) {
+        Some(indices) => indices.iter().map(|&i| nums[i]).product(),
+        None => panic!("No {} entries sum to {}", k, TARGET),
+    }
+}
+
+pub struct Day01;
+
+pub const DAY: Day = Day::new::<Day01>(1);
+
+impl Solution for Day01 {
+    // One entry per line, so an entry's index is its line number minus one
+    type Input = Vec<i64>;
+
+    fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+        parsers::lines(input, "an expense")
+    }
+
+    fn part1(nums: &Vec<i64>) -> Answer {
+        product_of(nums, 2).into()
+    }
+
+    fn part2(nums: &Vec<i64>) -> Answer {
+        product_of(nums, 3).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1721
+979
+366
+299
+675
+1456";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day01::part1(&Day01::parse(EXAMPLE).unwrap()), Answer::Number(514579));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day01::part2(&Day01::parse(EXAMPLE).unwrap()), Answer::Number(241861950));
+    }
+}
@@ -1,35 +1,158 @@
-use std::fs::File;
-use std::io::{self, BufReader, BufRead};
-use std::vec::Vec;
+//! Searches an expense report for entries that add up to a target, with more options than the
+//! puzzle needs.
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+use aoc_common::args::Args;
+use aoc_common::{input, Solution};
+use day01::{ksum, Day01};
+use std::error::Error;
+use std::path::Path;
+use std::process;
 
-    let mut nums: Vec<i32> = vec![];
+const USAGE: &str = "\
+Usage: day01 [--k K] [--target T] [--closest | --all] [--input PATH]
 
-    for line in reader.lines() {
-        let line = line?;
-        let val = line.parse().unwrap();
-        nums.push(val);
+Finds K entries (default 2) of the expense report that add up to T (default
+2020), and prints their line numbers, the entries and their product. Fails if
+there are none.
+
+--closest  Finds the entries whose sum is nearest to T instead, and how far off
+           it is.
+--all      Lists every set of entries that adds up to T, each set of lines only
+           once even if some entries are the same.
+
+The report is read from PATH, or from stdin if PATH is `-` or missing.";
+
+enum Mode {
+    Exact,
+    Closest,
+    All,
+}
+
+struct Options {
+    k: usize,
+    target: i64,
+    mode: Mode,
+    input: Option<String>,
+}
+
+fn parse_args(args: &Args) -> Result<Options, Box<dyn Error>> {
+    if let Some(arg) = args.command() {
+        return Err(format!("unexpected argument {}", arg).into());
     }
+    args.check(&["k", "target", "input"], &["closest", "all"])?;
 
-    for i in 0..nums.len() {
-        for j in i+1..nums.len() {
-            let x = nums[i];
-            let y = nums[j];
-            if x + y == 2020 {
-                println!("Found! (1) {} + {} = 2020, {} * {} = {}", x, y, x, y, x * y);
-            }
+    let mode = match (args.flag("closest"), args.flag("all")) {
+        (false, false) => Mode::Exact,
+        (true, false) => Mode::Closest,
+        (false, true) => Mode::All,
+        (true, true) => return Err("--closest and --all can't be used together".into()),
+    };
+
+    let k = args.value("k")?.unwrap_or(2);
+    if k == 0 {
+        return Err("--k must be at least 1".into());
+    }
+
+    Ok(Options {
+        k,
+        target: args.value("target")?.unwrap_or(2020),
+        mode,
+        input: args.get("input").map(str::to_string),
+    })
+}
+
+/// Describes a set of entries, as `lines 1, 4: 1721 + 299 = 2020`.
+fn describe(nums: &[i64], indices: &[usize]) -> String {
+    let lines: Vec<String> = indices.iter().map(|i| (i + 1).to_string()).collect();
+    let entries: Vec<String> = indices.iter().map(|&i| nums[i].to_string()).collect();
+    let sum: i64 = indices.iter().map(|&i| nums[i]).sum();
+
+    format!(
+        "lines {}: {} = {}",
+        lines.join(", "),
+        entries.join(" + "),
+        sum
+    )
+}
+
+fn product(nums: &[i64], indices: &[usize]) -> i128 {
+    indices.iter().map(|&i| i128::from(nums[i])).product()
+}
+
+fn run(options: &Options) -> Result<(), Box<dyn Error>> {
+    let path = options.input.as_deref().map(Path::new);
+    let nums = Day01::parse(&input::read(path)?).map_err(|e| e.with_file(input::name(path)))?;
+    let (k, target) = (options.k, options.target);
 
-            for k in j+1..nums.len() {
-                let z = nums[k];
-                if x + y + z == 2020 {
-                    println!("Found! (2) {} + {} + {} = 2020, {} * {} * {} = {}", x, y, z, x, y, z, x * y * z);
-                }
+    match options.mode {
+        Mode::Exact => {
+            let indices = ksum::find(&nums, k, target)
+                .ok_or_else(|| format!("no {} entries add up to {}", k, target))?;
+
+            println!(
+                "{}, product {}",
+                describe(&nums, &indices),
+                product(&nums, &indices)
+            );
+        }
+        Mode::Closest => {
+            let (indices, diff) = ksum::closest(&nums, k, target)
+                .ok_or_else(|| format!("there are fewer than {} entries", k))?;
+
+            println!(
+                "{}, {:+} from {}, product {}",
+                describe(&nums, &indices),
+                diff,
+                target,
+                product(&nums, &indices)
+            );
+        }
+        Mode::All => {
+            let found = ksum::all(&nums, k, target);
+
+            for indices in &found {
+                println!(
+                    "{}, product {}",
+                    describe(&nums, indices),
+                    product(&nums, indices)
+                );
             }
+
+            eprintln!("{} sets of {} entries add up to {}", found.len(), k, target);
         }
     }
 
     Ok(())
 }
+
+fn main() {
+    let options = match parse_args(&Args::from_env()) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(err) = run(&options) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Options, String> {
+        parse_args(&Args::parse(args.split_whitespace().map(str::to_string)))
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn rejects_no_entries() {
+        for args in &["--k 0", "--k 0 --closest", "--k 0 --all"] {
+            assert_eq!(parse(args).err().unwrap(), "--k must be at least 1");
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! A report of how every line of a password list fares against a set of policies, for audits.
+
+use crate::policy::Policy;
+use crate::{Password, PasswordPolicy};
+use serde::Serialize;
+
+/// How one line of the password list fares.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// The line number, counting from 1.
+    pub line: usize,
+    pub password: String,
+    /// Why the password fails each policy, in the same order as the policies, or `None` where it
+    /// passes.
+    pub failures: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Audit {
+    pub policies: Vec<String>,
+    pub lines: Vec<Line>,
+}
+
+#[derive(Serialize)]
+struct JsonLine<'a> {
+    line: usize,
+    password: &'a str,
+    passes: Vec<&'a str>,
+    failures: Vec<JsonFailure<'a>>,
+}
+
+#[derive(Serialize)]
+struct JsonFailure<'a> {
+    policy: &'a str,
+    reason: &'a str,
+}
+
+/// Quotes a CSV field if it needs it.
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\r', '\n'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Audit {
+    pub fn new(passwords: &[Password<'_>], policies: &[Policy]) -> Self {
+        let lines = passwords
+            .iter()
+            .enumerate()
+            .map(|(i, password)| Line {
+                line: i + 1,
+                password: password.to_string(),
+                failures: policies.iter().map(|p| p.spec.explain(password)).collect(),
+            })
+            .collect();
+
+        Self {
+            policies: policies.iter().map(|p| p.name.clone()).collect(),
+            lines,
+        }
+    }
+
+    /// Writes the report as CSV, with a column for each policy saying either `valid` or why the
+    /// password isn't.
+    pub fn to_csv(&self) -> String {
+        let mut header = vec!["line".to_string(), "password".to_string()];
+        header.extend(self.policies.iter().map(|p| csv_field(p)));
+
+        let mut ret = header.join(",") + "\n";
+
+        for line in &self.lines {
+            let mut row = vec![line.line.to_string(), csv_field(&line.password)];
+            row.extend(
+                line.failures
+                    .iter()
+                    .map(|f| csv_field(f.as_deref().unwrap_or("valid"))),
+            );
+
+            ret += &row.join(",");
+            ret += "\n";
+        }
+
+        ret
+    }
+
+    /// Writes the report as a JSON array with an object for each line, listing the policies it
+    /// passes and the ones it fails along with why.
+    pub fn to_json(&self) -> String {
+        let lines: Vec<JsonLine> = self
+            .lines
+            .iter()
+            .map(|line| {
+                let results = self.policies.iter().zip(&line.failures);
+
+                JsonLine {
+                    line: line.line,
+                    password: &line.password,
+                    passes: results
+                        .clone()
+                        .filter(|(_, f)| f.is_none())
+                        .map(|(p, _)| p.as_str())
+                        .collect(),
+                    failures: results
+                        .filter_map(|(p, f)| {
+                            f.as_deref().map(|reason| JsonFailure { policy: p, reason })
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        serde_json::to_string_pretty(&lines).expect("The report can always be serialized")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{policy, Day02};
+    use aoc_common::Solution;
+
+    fn audit() -> Audit {
+        let passwords = Day02::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+        let policies = policy::parse("part 1: occurrences\npart 2: positions\n").unwrap();
+
+        Audit::new(&passwords, &policies)
+    }
+
+    #[test]
+    fn writes_csv() {
+        assert_eq!(
+            audit().to_csv(),
+            "\
+line,password,part 1,part 2
+1,1-3 a: abcde,valid,valid
+2,1-3 b: cdefg,\"letter `b` appears 0 times, allowed 1-3\",neither position 1 nor 3 contains `b`
+3,2-9 c: ccccccccc,valid,positions 2 and 9 both contain `c`
+"
+        );
+    }
+
+    #[test]
+    fn writes_json() {
+        let json: serde_json::Value = serde_json::from_str(&audit().to_json()).unwrap();
+
+        assert_eq!(json[0]["passes"], serde_json::json!(["part 1", "part 2"]));
+        assert_eq!(json[2]["line"], 3);
+        assert_eq!(json[2]["passes"], serde_json::json!(["part 1"]));
+        assert_eq!(
+            json[2]["failures"],
+            serde_json::json!([{
+                "policy": "part 2",
+                "reason": "positions 2 and 9 both contain `c`"
+            }])
+        );
+    }
+}
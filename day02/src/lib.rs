@@ -0,0 +1,274 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    bytes::complete::tag, character::complete::anychar, combinator::rest, sequence::separated_pair,
+    sequence::terminated, IResult,
+};
+use std::borrow::Cow;
+use std::fmt;
+
+pub mod audit;
+pub mod policy;
+pub mod stream;
+
+/// A password and the rule on its line, which borrows the password from the input unless it's
+/// been made [owned](Password::into_owned).
+#[derive(Debug, Clone)]
+pub struct Password<'a> {
+    min: usize,
+    max: usize,
+    letter: char,
+    password: Cow<'a, str>,
+    // Most passwords are ASCII, so they can be indexed by byte instead of going through every
+    // character before the one wanted
+    ascii: bool,
+}
+
+fn parse_min_max(input: &str) -> IResult<&str, (usize, usize)> {
+    separated_pair(number, tag("-"), number)(input)
+}
+
+fn parse_rule(input: &str) -> IResult<&str, ((usize, usize), char)> {
+    separated_pair(parse_min_max, tag(" "), anychar)(input)
+}
+
+fn parse_password(input: &str) -> IResult<&str, Password<'_>> {
+    let (input, ((min, max), letter)) = terminated(parse_rule, tag(": "))(input)?;
+    // The password is the rest of the line, whatever's in it
+    let (input, password) = rest(input)?;
+
+    Ok((
+        input,
+        Password {
+            min,
+            max,
+            letter,
+            password: Cow::Borrowed(password),
+            ascii: password.is_ascii(),
+        },
+    ))
+}
+
+/// Parses one line of `source`, the whole password list.
+fn parse_line<'a>(source: &'a str, line: &'a str) -> Result<Password<'a>, ParseError> {
+    parse_all(source, line, "a password policy", parse_password)
+}
+
+/// Parses a password list without copying any of the passwords.
+pub fn parse_passwords(input: &str) -> Result<Vec<Password<'_>>, ParseError> {
+    input.lines().map(|line| parse_line(input, line)).collect()
+}
+
+impl Password<'_> {
+    /// Copies the password out of the input, so that it can outlive it.
+    pub fn into_owned(self) -> Password<'static> {
+        Password {
+            password: Cow::Owned(self.password.into_owned()),
+            ..self
+        }
+    }
+
+    /// The character at `position`, counting from 1, if the password is that long.
+    fn at(&self, position: usize) -> Option<char> {
+        let i = position.checked_sub(1)?;
+
+        if self.ascii {
+            self.password.as_bytes().get(i).map(|&b| char::from(b))
+        } else {
+            self.password.chars().nth(i)
+        }
+    }
+}
+
+impl fmt::Display for Password<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{} {}: {}",
+            self.min, self.max, self.letter, self.password
+        )
+    }
+}
+
+/// A rule that decides whether a password is valid.
+pub trait PasswordPolicy {
+    fn verify(&self, password: &Password<'_>) -> bool;
+
+    /// Explains why `password` isn't valid, or returns `None` if it is.
+    fn explain(&self, password: &Password<'_>) -> Option<String>;
+}
+
+/// The sled rental policy: the letter appears between `min` and `max` times.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrences;
+
+impl Occurrences {
+    fn count(password: &Password<'_>) -> usize {
+        // An ASCII byte is never part of a longer character in UTF-8, so bytes can be counted
+        if password.letter.is_ascii() {
+            let letter = password.letter as u8;
+            return password.password.bytes().filter(|&b| b == letter).count();
+        }
+
+        let mut occurrences = 0;
+
+        for letter in password.password.chars() {
+            if letter == password.letter {
+                occurrences += 1;
+            }
+        }
+
+        occurrences
+    }
+}
+
+impl PasswordPolicy for Occurrences {
+    fn verify(&self, password: &Password<'_>) -> bool {
+        let occurrences = Self::count(password);
+        occurrences >= password.min && occurrences <= password.max
+    }
+
+    fn explain(&self, password: &Password<'_>) -> Option<String> {
+        if self.verify(password) {
+            return None;
+        }
+
+        Some(format!(
+            "letter `{}` appears {} times, allowed {}-{}",
+            password.letter,
+            Self::count(password),
+            password.min,
+            password.max
+        ))
+    }
+}
+
+/// The Toboggan policy: the letter is at exactly one of the positions `min` and `max`, counting
+/// from 1. A password too short to have both positions isn't valid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Positions;
+
+impl PasswordPolicy for Positions {
+    fn verify(&self, password: &Password<'_>) -> bool {
+        match (password.at(password.min), password.at(password.max)) {
+            (Some(letter1), Some(letter2)) => {
+                (letter1 == password.letter) != (letter2 == password.letter)
+            }
+            _ => false,
+        }
+    }
+
+    fn explain(&self, password: &Password<'_>) -> Option<String> {
+        if self.verify(password) {
+            return None;
+        }
+
+        let (min, max, letter) = (password.min, password.max, password.letter);
+
+        Some(match (password.at(min), password.at(max)) {
+            (None, _) => format!("position {} is outside the password", min),
+            (_, None) => format!("position {} is outside the password", max),
+            (Some(c), _) if c == letter => {
+                format!("positions {} and {} both contain `{}`", min, max, letter)
+            }
+            _ => format!("neither position {} nor {} contains `{}`", min, max, letter),
+        })
+    }
+}
+
+pub fn count_valid(passwords: &[Password<'_>], policy: &dyn PasswordPolicy) -> usize {
+    passwords.iter().filter(|p| policy.verify(p)).count()
+}
+
+pub struct Day02;
+
+pub const DAY: Day = Day::new::<Day02>(2);
+
+impl Solution for Day02 {
+    type Input = Vec<Password<'static>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(parse_passwords(input)?
+            .into_iter()
+            .map(Password::into_owned)
+            .collect())
+    }
+
+    fn part1(passwords: &Self::Input) -> Answer {
+        count_valid(passwords, &Occurrences).into()
+    }
+
+    fn part2(passwords: &Self::Input) -> Answer {
+        count_valid(passwords, &Positions).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc";
+
+    #[test]
+    fn parses_password() {
+        let (_, password) = parse_password("1-3 a: abcde").unwrap();
+
+        assert_eq!(password.min, 1);
+        assert_eq!(password.max, 3);
+        assert_eq!(password.letter, 'a');
+        assert_eq!(password.password, "abcde");
+
+        let passwords = parse_passwords("1-3 a: ab cd\n1-3 a: ").unwrap();
+        assert_eq!(passwords[0].password, "ab cd");
+        assert_eq!(passwords[1].password, "");
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day02::part1(&Day02::parse(EXAMPLE).unwrap()), Answer::Number(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day02::part2(&Day02::parse(EXAMPLE).unwrap()), Answer::Number(1));
+    }
+
+    #[test]
+    fn explains_failures() {
+        let passwords = Day02::parse("1-3 a: aaaaa\n2-4 b: bcb\n1-4 c: cdee\n0-2 d: dd").unwrap();
+        let reasons = |policy: &dyn PasswordPolicy| -> Vec<Option<String>> {
+            passwords.iter().map(|p| policy.explain(p)).collect()
+        };
+
+        assert_eq!(
+            reasons(&Occurrences),
+            [
+                Some("letter `a` appears 5 times, allowed 1-3".to_string()),
+                None,
+                None,
+                None,
+            ]
+        );
+        assert_eq!(
+            reasons(&Positions),
+            [
+                Some("positions 1 and 3 both contain `a`".to_string()),
+                Some("position 4 is outside the password".to_string()),
+                None,
+                Some("position 0 is outside the password".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn counts_characters() {
+        let passwords = parse_passwords("1-2 é: éaé\n2-3 a: éaé\n1-1 b: ébé").unwrap();
+
+        assert_eq!(count_valid(&passwords, &Occurrences), 2);
+        assert_eq!(count_valid(&passwords, &Positions), 2);
+        assert!(matches!(passwords[0].password, Cow::Borrowed(_)));
+    }
+}
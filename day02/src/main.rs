@@ -1,85 +1,187 @@
-use nom::{
-    bytes::complete::tag, character::complete::anychar, character::complete::digit1,
-    combinator::map_res, sequence::separated_pair, sequence::terminated, IResult,
-};
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
-
-#[derive(Debug)]
-struct Password {
-    min: usize,
-    max: usize,
-    letter: char,
-    password: String,
+//! Checks a password list against any number of policies, with more options than the puzzle
+//! needs.
+
+use aoc_common::args::Args;
+use aoc_common::input;
+use day02::audit::Audit;
+use day02::policy::{self, Policy, Spec};
+use day02::stream::{self, Chunks, StreamError, Tally, CHUNK_SIZE};
+use memmap2::Mmap;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::Instant;
+
+const USAGE: &str = "\
+Usage: day02 [--policies PATH] [--report csv|json] [--threads T] [--input PATH]
+
+Counts how many passwords in the list are valid under each policy, and how many
+lines per second it got through. Without --policies, the two policies from the
+puzzle are used, named `occurrences` and `positions`.
+
+--policies  Reads the policies from PATH, one per line as `name: spec`. See the
+            docs of day02::policy for how specs are written.
+--report    Reports every password instead, with the policies it passes and
+            why it fails the others, as CSV or JSON. This needs the whole
+            list in memory.
+--threads   Counts on T threads (default one per CPU).
+
+The password list is read from PATH, or from stdin if PATH is `-` or missing.
+Unless it's being reported, it's counted a chunk at a time as it's read, so it
+can be as big as it likes.";
+
+enum Report {
+    Csv,
+    Json,
 }
 
-fn parse_digit<'a>(input: &'a str) -> IResult<&'a str, usize> {
-    map_res(digit1, |a: &str| a.parse())(input)
+struct Options {
+    policies: Option<String>,
+    report: Option<Report>,
+    threads: usize,
+    input: Option<String>,
 }
 
-fn parse_min_max<'a>(input: &'a str) -> IResult<&'a str, (usize, usize)> {
-    separated_pair(parse_digit, tag("-"), parse_digit)(input)
+fn parse_args(args: &Args) -> Result<Options, Box<dyn Error>> {
+    if let Some(arg) = args.command() {
+        return Err(format!("unexpected argument {}", arg).into());
+    }
+    args.check(&["policies", "report", "threads", "input"], &[])?;
+
+    let report = match args.get("report") {
+        None => None,
+        Some("csv") => Some(Report::Csv),
+        Some("json") => Some(Report::Json),
+        Some(report) => return Err(format!("invalid value for --report: {}", report).into()),
+    };
+
+    Ok(Options {
+        policies: args.get("policies").map(str::to_string),
+        report,
+        threads: match args.value("threads")? {
+            Some(threads) => threads,
+            None => thread::available_parallelism().map_or(1, |n| n.get()),
+        },
+        input: args.get("input").map(str::to_string),
+    })
 }
 
-fn parse_rule(input: &str) -> IResult<&str, ((usize, usize), char)> {
-    separated_pair(parse_min_max, tag(" "), anychar)(input)
+fn load_policies(path: Option<&str>) -> Result<Vec<Policy>, Box<dyn Error>> {
+    let path = match path {
+        Some(path) => Path::new(path),
+        None => {
+            return Ok(vec![
+                Policy {
+                    name: "occurrences".to_string(),
+                    spec: Spec::Occurrences,
+                },
+                Policy {
+                    name: "positions".to_string(),
+                    spec: Spec::Positions,
+                },
+            ])
+        }
+    };
+
+    let source = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok(policy::parse(&source).map_err(|e| e.with_file(path))?)
 }
 
-fn parse_password(input: &str) -> IResult<&str, Password> {
-    let (input, ((min, max), letter)) = terminated(parse_rule, tag(": "))(input)?;
+/// Counts the valid passwords a chunk at a time, from a memory-mapped file or from stdin.
+fn count(
+    path: Option<&Path>,
+    policies: &[Policy],
+    threads: usize,
+) -> Result<Tally, Box<dyn Error>> {
+    let result = match path {
+        Some(path) if path != Path::new("-") => {
+            let file = File::open(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+            // Only safe if nothing changes the file while it's mapped, which nothing should be
+            // doing to a password list
+            let map = unsafe { Mmap::map(&file)? };
+            stream::tally(stream::split(&map, CHUNK_SIZE), policies, threads)
+        }
+        _ => stream::tally(Chunks::new(io::stdin(), CHUNK_SIZE), policies, threads),
+    };
 
-    Ok((
-        "",
-        Password {
-            min,
-            max,
-            letter,
-            password: input.to_string(),
-        },
-    ))
+    result.map_err(|err| match err {
+        StreamError::Parse(err) => err.with_file(input::name(path)).into(),
+        err => err.into(),
+    })
 }
 
-fn verify_password1(password: &Password) -> bool {
-    let mut occurrences = 0;
+fn run(options: &Options) -> Result<(), Box<dyn Error>> {
+    let policies = load_policies(options.policies.as_deref())?;
+    let path = options.input.as_deref().map(Path::new);
 
-    for letter in password.password.chars() {
-        if letter == password.letter {
-            occurrences += 1;
-        }
+    match &options.report {
+        Some(report) => print_report(path, &policies, report),
+        None => print_counts(path, &policies, options.threads),
     }
-
-    occurrences >= password.min && occurrences <= password.max
 }
 
-fn verify_password2(password: &Password) -> bool {
-    let letter1 = password.password.chars().nth(password.min - 1).unwrap();
-    let letter2 = password.password.chars().nth(password.max - 1).unwrap();
+fn print_report(
+    path: Option<&Path>,
+    policies: &[Policy],
+    report: &Report,
+) -> Result<(), Box<dyn Error>> {
+    let source = input::read(path)?;
+    let passwords = day02::parse_passwords(&source).map_err(|e| e.with_file(input::name(path)))?;
+    let audit = Audit::new(&passwords, policies);
+
+    match report {
+        Report::Csv => print!("{}", audit.to_csv()),
+        Report::Json => println!("{}", audit.to_json()),
+    }
 
-    (letter1 == password.letter && letter2 != password.letter)
-        || (letter1 != password.letter && letter2 == password.letter)
+    Ok(())
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+fn print_counts(
+    path: Option<&Path>,
+    policies: &[Policy],
+    threads: usize,
+) -> Result<(), Box<dyn Error>> {
+    let start = Instant::now();
+    let tally = count(path, policies, threads)?;
+    let secs = start.elapsed().as_secs_f64();
+
+    let width = policies.iter().map(|p| p.name.len()).max().unwrap_or(0);
+    for (policy, valid) in policies.iter().zip(&tally.valid) {
+        println!(
+            "{:width$}  {} of {} valid",
+            policy.name,
+            valid,
+            tally.lines,
+            width = width
+        );
+    }
 
-    let mut valid1 = 0;
-    let mut valid2 = 0;
+    eprintln!(
+        "checked {} lines in {:.2}s, {:.0} lines/s",
+        tally.lines,
+        secs,
+        tally.lines as f64 / secs.max(1e-9)
+    );
 
-    for line in reader.lines() {
-        let line = line?;
-        let (_, password) = parse_password(&line).unwrap();
-        if verify_password1(&password) {
-            valid1 += 1;
-        }
+    Ok(())
+}
 
-        if verify_password2(&password) {
-            valid2 += 1;
+fn main() {
+    let options = match parse_args(&Args::from_env()) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
         }
-    }
-
-    println!("(1) {} valid passwords!", valid1);
-    println!("(2) {} valid passwords!", valid2);
+    };
 
-    Ok(())
+    if let Err(err) = run(&options) {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,438 @@
+//! Password policies written in a small spec language, so that they can be loaded from a file.
+//!
+//! A policy file has one policy per line, as `name: spec`. Blank lines and lines starting with `#`
+//! are skipped. A spec is made of these checks:
+//!
+//! - `length 8-64`, `length 8-`, `length -64` or `length 8`: how many characters there are.
+//! - `has CLASS` and `no CLASS`: whether any character is in the class.
+//! - `at N CLASS`: whether the character at position `N`, counting from 1, is in the class. A
+//!   password too short to have one isn't.
+//! - `occurrences` and `positions`: the two policies from the puzzle, which use the rule at the
+//!   start of each line of the password list.
+//!
+//! A class is one of `digit`, `lower`, `upper`, `alpha`, `letter` for the letter of the rule, a
+//! single character as `'x'` or a set of them as `[xyz]`.
+//!
+//! Checks combine with `not`, `and` and `or`, in that order of precedence, and with parentheses:
+//!
+//! ```text
+//! strong: length 12- and has digit and has upper and not (no lower or at 1 digit)
+//! ```
+
+use crate::{Occurrences, Password, PasswordPolicy, Positions};
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::ParseError;
+use nom::{
+    branch::alt, bytes::complete::is_not, bytes::complete::tag, character::complete::anychar,
+    character::complete::space0, character::complete::space1, combinator::map, combinator::opt,
+    combinator::value, combinator::verify, multi::separated_list1, sequence::delimited,
+    sequence::pair, sequence::preceded, sequence::separated_pair, sequence::terminated, IResult,
+};
+use std::fmt;
+
+/// A set of characters that a check looks for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Class {
+    Digit,
+    Lower,
+    Upper,
+    Alpha,
+    /// The letter of the rule on the password's line.
+    Letter,
+    Chars(String),
+}
+
+impl Class {
+    fn contains(&self, c: char, password: &Password<'_>) -> bool {
+        match self {
+            Class::Digit => c.is_ascii_digit(),
+            Class::Lower => c.is_lowercase(),
+            Class::Upper => c.is_uppercase(),
+            Class::Alpha => c.is_alphabetic(),
+            Class::Letter => c == password.letter,
+            Class::Chars(chars) => chars.contains(c),
+        }
+    }
+
+    /// Describes the class in words, for explaining why a password fails.
+    fn describe(&self, password: &Password<'_>) -> String {
+        match self {
+            Class::Digit => "a digit".to_string(),
+            Class::Lower => "a lowercase letter".to_string(),
+            Class::Upper => "an uppercase letter".to_string(),
+            Class::Alpha => "a letter".to_string(),
+            Class::Letter => format!("`{}`", password.letter),
+            Class::Chars(chars) if chars.chars().count() == 1 => format!("`{}`", chars),
+            Class::Chars(chars) => format!("one of `{}`", chars),
+        }
+    }
+}
+
+/// Writes the class as it's written in a spec.
+impl fmt::Display for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Class::Digit => write!(f, "digit"),
+            Class::Lower => write!(f, "lower"),
+            Class::Upper => write!(f, "upper"),
+            Class::Alpha => write!(f, "alpha"),
+            Class::Letter => write!(f, "letter"),
+            Class::Chars(chars) if chars.chars().count() == 1 => write!(f, "'{}'", chars),
+            Class::Chars(chars) => write!(f, "[{}]", chars),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Spec {
+    /// Between `min` and `max` characters long, inclusive.
+    Length(usize, usize),
+    Has(Class),
+    At(usize, Class),
+    Occurrences,
+    Positions,
+    Not(Box<Spec>),
+    And(Vec<Spec>),
+    Or(Vec<Spec>),
+}
+
+/// Writes `min` and `max` as they're written after `length`.
+fn bounds(min: usize, max: usize) -> String {
+    match (min, max) {
+        _ if min == max => min.to_string(),
+        (0, usize::MAX) => "0-".to_string(),
+        (0, _) => format!("-{}", max),
+        (_, usize::MAX) => format!("{}-", min),
+        _ => format!("{}-{}", min, max),
+    }
+}
+
+impl PasswordPolicy for Spec {
+    fn verify(&self, password: &Password<'_>) -> bool {
+        let chars = || password.password.chars();
+
+        match self {
+            Spec::Length(min, max) => (min..=max).contains(&&chars().count()),
+            Spec::Has(class) => chars().any(|c| class.contains(c, password)),
+            Spec::At(n, class) => password.at(*n).is_some_and(|c| class.contains(c, password)),
+            Spec::Occurrences => Occurrences.verify(password),
+            Spec::Positions => Positions.verify(password),
+            Spec::Not(spec) => !spec.verify(password),
+            Spec::And(specs) => specs.iter().all(|s| s.verify(password)),
+            Spec::Or(specs) => specs.iter().any(|s| s.verify(password)),
+        }
+    }
+
+    fn explain(&self, password: &Password<'_>) -> Option<String> {
+        if self.verify(password) {
+            return None;
+        }
+
+        Some(match self {
+            Spec::Length(min, max) => format!(
+                "{} characters long, allowed {}",
+                password.password.chars().count(),
+                bounds(*min, *max)
+            ),
+            Spec::Has(class) => format!("no character is {}", class.describe(password)),
+            Spec::At(n, class) => match password.at(*n) {
+                Some(c) => format!(
+                    "position {} is `{}`, not {}",
+                    n,
+                    c,
+                    class.describe(password)
+                ),
+                None => format!("position {} is outside the password", n),
+            },
+            Spec::Occurrences => return Occurrences.explain(password),
+            Spec::Positions => return Positions.explain(password),
+            Spec::Not(spec) => match &**spec {
+                Spec::Has(class) => format!("contains {}", class.describe(password)),
+                spec => format!("`{}` holds", spec),
+            },
+            // Every reason an `and` fails is worth knowing, and an `or` fails for all of them
+            Spec::And(specs) | Spec::Or(specs) => specs
+                .iter()
+                .filter_map(|s| s.explain(password))
+                .collect::<Vec<_>>()
+                .join("; "),
+        })
+    }
+}
+
+/// Writes the spec back out in the spec language, with only the parentheses it needs.
+impl fmt::Display for Spec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, specs: &[Spec], separator: &str| {
+            for (i, spec) in specs.iter().enumerate() {
+                if i > 0 {
+                    write!(f, " {} ", separator)?;
+                }
+
+                match spec {
+                    Spec::Or(_) if separator == "and" => write!(f, "({})", spec)?,
+                    _ => write!(f, "{}", spec)?,
+                }
+            }
+
+            Ok(())
+        };
+
+        match self {
+            Spec::Length(min, max) => write!(f, "length {}", bounds(*min, *max)),
+            Spec::Has(class) => write!(f, "has {}", class),
+            Spec::At(n, class) => write!(f, "at {} {}", n, class),
+            Spec::Occurrences => write!(f, "occurrences"),
+            Spec::Positions => write!(f, "positions"),
+            Spec::Not(spec) => match &**spec {
+                Spec::Has(class) => write!(f, "no {}", class),
+                Spec::And(_) | Spec::Or(_) => write!(f, "not ({})", spec),
+                spec => write!(f, "not {}", spec),
+            },
+            Spec::And(specs) => join(f, specs, "and"),
+            Spec::Or(specs) => join(f, specs, "or"),
+        }
+    }
+}
+
+/// A spec loaded from a policy file, along with its name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    pub name: String,
+    pub spec: Spec,
+}
+
+fn parse_class(input: &str) -> IResult<&str, Class> {
+    alt((
+        value(Class::Digit, tag("digit")),
+        value(Class::Lower, tag("lower")),
+        value(Class::Upper, tag("upper")),
+        value(Class::Alpha, tag("alpha")),
+        value(Class::Letter, tag("letter")),
+        map(delimited(tag("'"), anychar, tag("'")), |c| {
+            Class::Chars(c.to_string())
+        }),
+        map(delimited(tag("["), is_not("]"), tag("]")), |chars: &str| {
+            Class::Chars(chars.to_string())
+        }),
+    ))(input)
+}
+
+fn parse_bounds(input: &str) -> IResult<&str, (usize, usize)> {
+    alt((
+        verify(
+            map(
+                separated_pair(opt(number), tag("-"), opt(number)),
+                |(min, max)| (min.unwrap_or(0), max.unwrap_or(usize::MAX)),
+            ),
+            |&(min, max)| min <= max,
+        ),
+        map(number, |n| (n, n)),
+    ))(input)
+}
+
+fn parse_check(input: &str) -> IResult<&str, Spec> {
+    alt((
+        map(
+            preceded(pair(tag("length"), space1), parse_bounds),
+            |(min, max)| Spec::Length(min, max),
+        ),
+        map(preceded(pair(tag("has"), space1), parse_class), Spec::Has),
+        map(preceded(pair(tag("no"), space1), parse_class), |class| {
+            Spec::Not(Box::new(Spec::Has(class)))
+        }),
+        map(
+            preceded(
+                pair(tag("at"), space1),
+                separated_pair(verify(number, |&n| n > 0), space1, parse_class),
+            ),
+            |(n, class)| Spec::At(n, class),
+        ),
+        value(Spec::Occurrences, tag("occurrences")),
+        value(Spec::Positions, tag("positions")),
+        delimited(pair(tag("("), space0), parse_or, pair(space0, tag(")"))),
+    ))(input)
+}
+
+fn parse_not(input: &str) -> IResult<&str, Spec> {
+    alt((
+        map(preceded(pair(tag("not"), space1), parse_not), |spec| {
+            Spec::Not(Box::new(spec))
+        }),
+        parse_check,
+    ))(input)
+}
+
+/// Parses checks joined by `separator`, without wrapping a lone check.
+fn parse_joined<'a>(
+    separator: &'static str,
+    parse: fn(&'a str) -> IResult<&'a str, Spec>,
+    join: fn(Vec<Spec>) -> Spec,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Spec> {
+    map(
+        separated_list1(delimited(space1, tag(separator), space1), parse),
+        move |mut specs| {
+            if specs.len() == 1 {
+                specs.remove(0)
+            } else {
+                join(specs)
+            }
+        },
+    )
+}
+
+fn parse_and(input: &str) -> IResult<&str, Spec> {
+    parse_joined("and", parse_not, Spec::And)(input)
+}
+
+fn parse_or(input: &str) -> IResult<&str, Spec> {
+    parse_joined("or", parse_and, Spec::Or)(input)
+}
+
+fn parse_policy(input: &str) -> IResult<&str, Policy> {
+    let (input, name) = terminated(is_not(":"), pair(tag(":"), space0))(input)?;
+    let (input, spec) = parse_or(input)?;
+
+    Ok((
+        input,
+        Policy {
+            name: name.trim().to_string(),
+            spec,
+        },
+    ))
+}
+
+/// Parses a policy file.
+pub fn parse(input: &str) -> Result<Vec<Policy>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|line| parse_all(input, line, "a policy", parse_policy))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_valid, Day02};
+    use aoc_common::Solution;
+
+    fn password(line: &str) -> Password<'_> {
+        Day02::parse(line).unwrap().remove(0)
+    }
+
+    fn spec(input: &str) -> Spec {
+        parse_all(input, input, "a spec", parse_or).unwrap()
+    }
+
+    #[test]
+    fn parses_policies() {
+        let policies = parse(
+            "\
+# The puzzle's own
+part 1: occurrences
+
+part 2 : positions
+short: length -3 or at 4 'x'
+",
+        )
+        .unwrap();
+
+        let names: Vec<&str> = policies.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["part 1", "part 2", "short"]);
+        assert_eq!(
+            policies[2].spec,
+            Spec::Or(vec![
+                Spec::Length(0, 3),
+                Spec::At(4, Class::Chars("x".to_string()))
+            ])
+        );
+
+        let err = parse("ok: occurrences\nbad: length\n").unwrap_err();
+        assert_eq!(err.line, 2);
+        let err = parse("bad: at 0 digit\n").unwrap_err();
+        assert_eq!(err.line, 1);
+        let err = parse("ok: length 3-9\nbad: length 9-3\n").unwrap_err();
+        assert_eq!(err.line, 2);
+    }
+
+    #[test]
+    fn combines_in_order() {
+        assert_eq!(
+            spec("not has digit and (no upper or has letter) or length 8"),
+            Spec::Or(vec![
+                Spec::And(vec![
+                    Spec::Not(Box::new(Spec::Has(Class::Digit))),
+                    Spec::Or(vec![
+                        Spec::Not(Box::new(Spec::Has(Class::Upper))),
+                        Spec::Has(Class::Letter),
+                    ]),
+                ]),
+                Spec::Length(8, 8),
+            ])
+        );
+    }
+
+    #[test]
+    fn verifies_passwords() {
+        let p = password("1-3 a: Secr3tab");
+
+        assert!(spec("length 8-").verify(&p));
+        assert!(!spec("length -7").verify(&p));
+        assert!(spec("has digit and has upper and has [xyz!] or at 1 upper").verify(&p));
+        assert!(!spec("no digit").verify(&p));
+        assert!(spec("at 5 digit and at 7 letter and not at 8 letter").verify(&p));
+        assert!(!spec("at 9 alpha").verify(&p));
+        assert!(spec("occurrences and not positions").verify(&p));
+    }
+
+    #[test]
+    fn matches_the_puzzle() {
+        let passwords = Day02::parse("1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc").unwrap();
+
+        assert_eq!(count_valid(&passwords, &spec("occurrences")), 2);
+        assert_eq!(count_valid(&passwords, &spec("positions")), 1);
+        assert_eq!(
+            count_valid(&passwords, &spec("(at 1 letter or at 3 letter) and no 'e'")),
+            1
+        );
+    }
+
+    #[test]
+    fn explains_failures() {
+        let p = password("1-3 a: Secr3tab");
+        let explain = |input| spec(input).explain(&p);
+
+        assert_eq!(explain("length 8-"), None);
+        assert_eq!(
+            explain("length 12- and has [!?] and no digit"),
+            Some(
+                "8 characters long, allowed 12-; no character is one of `!?`; contains a digit"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            explain("at 1 lower or at 20 upper"),
+            Some(
+                "position 1 is `S`, not a lowercase letter; position 20 is outside the password"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            explain("not (at 7 letter and length 8)"),
+            Some("`at 7 letter and length 8` holds".to_string())
+        );
+    }
+
+    #[test]
+    fn writes_specs() {
+        for input in [
+            "length 8- and (has digit or no 'x') and not (at 1 [ab] or positions)",
+            "not occurrences or length -4 and has upper",
+            "length 3-5 or length 6",
+            "length 0- or no digit",
+        ] {
+            assert_eq!(spec(input).to_string(), input);
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//! Checking password lists too big to load all at once, such as breach dumps, against policies.
+//!
+//! The list is cut into chunks of whole lines, either from a memory-mapped file with [`split`] or
+//! read as it comes with [`Chunks`], and a pool of threads parses and checks the chunks as they're
+//! needed. Only one chunk per thread is ever held in memory, and the passwords are never copied.
+
+use crate::policy::Policy;
+use crate::{parse_line, PasswordPolicy};
+use aoc_common::{parallel, ParseError};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read};
+use std::iter;
+use std::mem;
+use std::str;
+
+/// How many bytes go in a chunk, give or take a line.
+pub const CHUNK_SIZE: usize = 1 << 20;
+
+/// A chunk of whole lines, which is borrowed when it comes from memory and owned when it was read.
+pub type Chunk<'a> = Cow<'a, [u8]>;
+
+/// Cuts `bytes` into chunks of whole lines, each at least `size` bytes long except the last.
+pub fn split(bytes: &[u8], size: usize) -> impl Iterator<Item = io::Result<Chunk<'_>>> {
+    let mut rest = bytes;
+
+    iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest
+            .get(size..)
+            .and_then(|after| after.iter().position(|&b| b == b'\n'))
+            .map_or(rest.len(), |i| size + i + 1);
+
+        let (chunk, after) = rest.split_at(end);
+        rest = after;
+        Some(Ok(Cow::Borrowed(chunk)))
+    })
+}
+
+/// Reads chunks of whole lines from a reader, each of about `size` bytes plus whatever was left
+/// of the line the last chunk cut off.
+#[derive(Debug)]
+pub struct Chunks<R> {
+    reader: R,
+    size: usize,
+    // The start of a line that didn't fit in the last chunk
+    rest: Vec<u8>,
+    done: bool,
+}
+
+impl<R: Read> Chunks<R> {
+    pub fn new(reader: R, size: usize) -> Self {
+        Self {
+            reader,
+            size: size.max(1),
+            rest: vec![],
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for Chunks<R> {
+    type Item = io::Result<Chunk<'static>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut chunk = mem::take(&mut self.rest);
+
+        loop {
+            let read = match (&mut self.reader)
+                .take(self.size as u64)
+                .read_to_end(&mut chunk)
+            {
+                Ok(read) => read,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            };
+
+            if read == 0 {
+                self.done = true;
+                return if chunk.is_empty() {
+                    None
+                } else {
+                    Some(Ok(Cow::Owned(chunk)))
+                };
+            }
+
+            // A line longer than a chunk just makes the chunk longer
+            if let Some(end) = chunk.iter().rposition(|&b| b == b'\n') {
+                self.rest = chunk.split_off(end + 1);
+                return Some(Ok(Cow::Owned(chunk)));
+            }
+        }
+    }
+}
+
+/// How many lines were checked, and how many of them each policy found valid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tally {
+    pub lines: u64,
+    /// In the same order as the policies.
+    pub valid: Vec<u64>,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StreamError::Io(err) => write!(f, "{}", err),
+            StreamError::Parse(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for StreamError {}
+
+/// Checks one chunk. Errors are placed as if the chunk was the whole input.
+fn tally_chunk(chunk: &[u8], policies: &[Policy]) -> Result<Tally, ParseError> {
+    let text = str::from_utf8(chunk).map_err(|err| {
+        let (valid, rest) = chunk.split_at(err.valid_up_to());
+        let valid = str::from_utf8(valid).unwrap();
+        let bad = &rest[..err.error_len().unwrap_or(rest.len())];
+        let escaped: String = bad.iter().map(|b| format!("\\x{:02x}", b)).collect();
+
+        // The position comes from the text before the bad bytes, which is all valid
+        ParseError {
+            text: String::from_utf8_lossy(rest)
+                .lines()
+                .next()
+                .unwrap_or("")
+                .to_string(),
+            ..ParseError::at(
+                valid,
+                &valid[valid.len()..],
+                format!("expected UTF-8 text instead of {}", escaped),
+            )
+        }
+    })?;
+
+    let mut tally = Tally {
+        lines: 0,
+        valid: vec![0; policies.len()],
+    };
+
+    for line in text.lines() {
+        let password = parse_line(text, line)?;
+        tally.lines += 1;
+
+        for (valid, policy) in tally.valid.iter_mut().zip(policies) {
+            if policy.spec.verify(&password) {
+                *valid += 1;
+            }
+        }
+    }
+
+    Ok(tally)
+}
+
+/// Checks every line of every chunk against the policies, on `threads` threads.
+///
+/// The chunks are taken one at a time by whichever thread is free, so reading the next chunk
+/// happens alongside checking the ones before it. It all stops at the first error, which is the
+/// one nearest the start of the input.
+pub fn tally<'a, I>(chunks: I, policies: &[Policy], threads: usize) -> Result<Tally, StreamError>
+where
+    I: Iterator<Item = io::Result<Chunk<'a>>> + Send,
+{
+    let results = parallel::try_map(chunks, threads, |chunk| match chunk {
+        Ok(chunk) => tally_chunk(&chunk, policies).map_err(StreamError::Parse),
+        Err(err) => Err(StreamError::Io(err)),
+    });
+
+    // Every chunk before a failed one has a result, so the lines in them can be counted to know
+    // where in the whole input the error is
+    let mut total = Tally {
+        lines: 0,
+        valid: vec![0; policies.len()],
+    };
+
+    for result in results {
+        match result {
+            Ok(tally) => {
+                total.lines += tally.lines;
+                for (total, valid) in total.valid.iter_mut().zip(tally.valid) {
+                    *total += valid;
+                }
+            }
+            Err(StreamError::Parse(err)) => {
+                return Err(StreamError::Parse(ParseError {
+                    line: err.line + total.lines as usize,
+                    ..err
+                }))
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{count_valid, policy, Day02};
+    use aoc_common::Solution;
+
+    const EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    fn policies() -> Vec<Policy> {
+        policy::parse("1: occurrences\n2: positions\n3: length 6- and no 'e'\n").unwrap()
+    }
+
+    fn chunks(chunks: Vec<io::Result<Chunk<'_>>>) -> Vec<Vec<u8>> {
+        chunks
+            .into_iter()
+            .map(|c| c.unwrap().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn splits_into_lines() {
+        let bytes = EXAMPLE.as_bytes();
+        let lines: Vec<&[u8]> = EXAMPLE.split_inclusive('\n').map(str::as_bytes).collect();
+
+        // Splitting goes past the size to the end of the line, reading stops before it
+        assert_eq!(
+            chunks(split(bytes, 20).collect()),
+            [lines[..2].concat(), lines[2].to_vec()]
+        );
+        assert_eq!(chunks(Chunks::new(bytes, 20).collect()), lines);
+
+        assert_eq!(chunks(split(bytes, 1).collect()), lines);
+        assert_eq!(chunks(Chunks::new(bytes, 1).collect()), lines);
+        assert_eq!(chunks(split(bytes, 1 << 10).collect()), [bytes]);
+        assert_eq!(chunks(Chunks::new(bytes, 1 << 10).collect()), [bytes]);
+
+        // The last line doesn't need a newline
+        let bytes = &bytes[..bytes.len() - 1];
+        assert_eq!(chunks(Chunks::new(bytes, 20).collect()).concat(), bytes);
+        assert_eq!(chunks(split(bytes, 20).collect()).concat(), bytes);
+    }
+
+    #[test]
+    fn counts_every_chunk() {
+        let input = EXAMPLE.repeat(100);
+        let passwords = Day02::parse(&input).unwrap();
+        let policies = policies();
+
+        let expected = Tally {
+            lines: 300,
+            valid: policies
+                .iter()
+                .map(|p| count_valid(&passwords, &p.spec) as u64)
+                .collect(),
+        };
+
+        for threads in 1..=4 {
+            let found = tally(split(input.as_bytes(), 50), &policies, threads).unwrap();
+            assert_eq!(found, expected);
+
+            let found = tally(Chunks::new(input.as_bytes(), 50), &policies, threads).unwrap();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn finds_the_first_error() {
+        let mut input = EXAMPLE.repeat(50).into_bytes();
+        input.extend(b"1-3 a: ok\n1-3 a\n");
+        input.extend(EXAMPLE.repeat(50).into_bytes());
+        input.extend(b"1-3 a: \xff\n");
+
+        for threads in 1..=4 {
+            match tally(split(&input, 64), &policies(), threads) {
+                Err(StreamError::Parse(err)) => assert_eq!((err.line, err.column), (152, 6)),
+                other => panic!("{:?}", other),
+            }
+        }
+
+        match tally(split(&input[input.len() - 9..], 64), &policies(), 2) {
+            Err(StreamError::Parse(err)) => {
+                assert_eq!((err.line, err.column), (1, 8));
+                assert_eq!(
+                    err.to_string(),
+                    "<input>:1:8: expected UTF-8 text instead of \\xff, found \"\u{fffd}\""
+                );
+            }
+            other => panic!("{:?}", other),
+        }
+    }
+}
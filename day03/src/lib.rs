@@ -0,0 +1,101 @@
+use aoc_common::{Answer, Day, Grid, ParseError, Solution};
+
+#[derive(Debug)]
+pub struct Map {
+    trees: Grid<bool>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Map, ParseError> {
+        let trees = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Map { trees })
+    }
+
+    // The map repeats to the right, but not downwards
+    fn get_at(&self, x: usize, y: usize) -> Option<bool> {
+        if y < self.trees.height() {
+            Some(*self.trees.get_wrapping(x, y))
+        } else {
+            None
+        }
+    }
+
+    fn get_encounters(&self, i: usize, j: usize) -> usize {
+        let mut encounters = 0;
+
+        let mut x = i;
+        let mut y = j;
+
+        loop {
+            match self.get_at(x, y) {
+                Some(true) => encounters += 1,
+                None => break,
+                _ => {}
+            }
+
+            x += i;
+            y += j;
+        }
+
+        encounters
+    }
+}
+
+pub struct Day03;
+
+pub const DAY: Day = Day::new::<Day03>(3);
+
+impl Solution for Day03 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Map, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(map: &Map) -> Answer {
+        map.get_encounters(3, 1).into()
+    }
+
+    fn part2(map: &Map) -> Answer {
+        let encounters11 = map.get_encounters(1, 1);
+        let encounters31 = map.get_encounters(3, 1);
+        let encounters51 = map.get_encounters(5, 1);
+        let encounters71 = map.get_encounters(7, 1);
+        let encounters12 = map.get_encounters(1, 2);
+
+        (encounters11 * encounters31 * encounters51 * encounters71 * encounters12).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day03::part1(&Day03::parse(EXAMPLE).unwrap()), Answer::Number(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day03::part2(&Day03::parse(EXAMPLE).unwrap()), Answer::Number(336));
+    }
+}
@@ -0,0 +1,252 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    bytes::complete::tag, bytes::complete::take_while,
+    multi::separated_list1, bytes::complete::take_while_m_n,
+    combinator::map, combinator::value, sequence::preceded, IResult, sequence::tuple,
+    branch::alt,
+};
+use std::vec::Vec;
+
+#[derive(Debug, Clone)]
+pub enum PassportField {
+    BirthYear(usize),
+    IssueYear(usize),
+    ExpirationYear(usize),
+    HairColor(String),
+    EyeColor(String),
+    PassportId(String),
+    CountryId,
+    Height(String),
+}
+
+use PassportField::*;
+
+pub type Passport = Vec<PassportField>;
+
+fn is_val(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '#'
+}
+
+fn parse_word(input: &str) -> IResult<&str, String> {
+    map(take_while(is_val), String::from)(input)
+}
+
+fn parse_field(input: &str) -> IResult<&str, PassportField> {
+    alt((
+        map(preceded(tag("byr:"), number), BirthYear),
+        map(preceded(tag("iyr:"), number), IssueYear),
+        map(preceded(tag("eyr:"), number), ExpirationYear),
+        map(preceded(tag("hcl:"), parse_word), HairColor),
+        map(preceded(tag("ecl:"), parse_word), EyeColor),
+        map(preceded(tag("pid:"), parse_word), PassportId),
+        value(CountryId, preceded(tag("cid:"), parse_word)),
+        map(preceded(tag("hgt:"), parse_word), Height),
+    ))(input)
+}
+
+fn parse_whitespace(input: &str) -> IResult<&str, &str> {
+    alt((tag(" "), tag("\t"), tag("\n"), tag("\r\n")))(input)
+}
+
+fn parse_fields(input: &str) -> IResult<&str, Passport> {
+    separated_list1(parse_whitespace, parse_field)(input)
+}
+
+fn parse_passports(input: &str) -> IResult<&str, Vec<Passport>> {
+    separated_list1(tag("\n\n"), parse_fields)(input)
+}
+
+fn is_valid1(passport: &Passport) -> bool {
+    let mut found_byr = false;
+    let mut found_iyr = false;
+    let mut found_eyr = false;
+    let mut found_hcl = false;
+    let mut found_ecl = false;
+    let mut found_pid = false;
+    let mut found_hgt = false;
+
+    for field in passport.iter() {
+        match field {
+            BirthYear(_) => found_byr = true,
+            IssueYear(_) => found_iyr = true,
+            ExpirationYear(_) => found_eyr = true,
+            HairColor(_) => found_hcl = true,
+            EyeColor(_) => found_ecl = true,
+            PassportId(_) => found_pid = true,
+            Height(_) => found_hgt = true,
+            _ => {},
+        }
+    }
+
+    found_hgt && found_pid && found_ecl && found_hcl && found_eyr && found_iyr && found_byr
+}
+
+fn is_hex_digit(c: char) -> bool {
+    c.is_ascii_hexdigit()
+}
+
+fn parse_hcl(input: &str) -> IResult<&str, &str> {
+    preceded(tag("#"), take_while_m_n(6, 6, is_hex_digit))(input)
+}
+
+fn is_hcl(c: &str) -> bool {
+    matches!(parse_hcl(c), Ok(("", _)))
+}
+
+fn parse_pid(input: &str) -> IResult<&str, &str> {
+    take_while_m_n(9, 9, |c: char| c.is_ascii_digit())(input)
+}
+
+fn is_pid(c: &str) -> bool {
+    matches!(parse_pid(c), Ok(("", _)))
+}
+
+fn parse_hgt(input: &str) -> IResult<&str, bool> {
+    let (input, (num, unit)) = tuple((number::<usize>, alt((tag("in"), tag("cm")))))(input)?;
+
+    if unit == "in" {
+        Ok((input, (59..=76).contains(&num)))
+    } else {
+        Ok((input, (150..=193).contains(&num)))
+    }
+}
+
+fn is_hgt(c: &str) -> bool {
+    if let Ok(("", r)) = parse_hgt(c) {
+        r
+    } else {
+        false
+    }
+}
+
+fn is_valid2(passport: &Passport) -> bool {
+    let mut found_byr = false;
+    let mut found_iyr = false;
+    let mut found_eyr = false;
+    let mut found_hcl = false;
+    let mut found_ecl = false;
+    let mut found_pid = false;
+    let mut found_hgt = false;
+
+    for field in passport.iter() {
+        match field {
+            BirthYear(y) => found_byr = (1920..=2002).contains(y),
+            IssueYear(y) => found_iyr = (2010..=2020).contains(y),
+            ExpirationYear(y) => found_eyr = (2020..=2030).contains(y),
+            HairColor(c) => found_hcl = is_hcl(c),
+            EyeColor(c) => found_ecl = matches!(
+                c.as_str(),
+                "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"
+            ),
+            PassportId(i) => found_pid = is_pid(i),
+            Height(h) => found_hgt = is_hgt(h),
+            _ => {},
+        }
+    }
+
+    found_hgt && found_pid && found_ecl && found_hcl && found_eyr && found_iyr && found_byr
+}
+
+fn count_valid(passports: &[Passport], is_valid: fn(&Passport) -> bool) -> usize {
+    passports.iter().filter(|p| is_valid(p)).count()
+}
+
+pub struct Day04;
+
+pub const DAY: Day = Day::new::<Day04>(4);
+
+impl Solution for Day04 {
+    type Input = Vec<Passport>;
+
+    fn parse(input: &str) -> Result<Vec<Passport>, ParseError> {
+        parse_all(input, input, "a passport field", parse_passports)
+    }
+
+    fn part1(passports: &Vec<Passport>) -> Answer {
+        count_valid(passports, is_valid1).into()
+    }
+
+    fn part2(passports: &Vec<Passport>) -> Answer {
+        count_valid(passports, is_valid2).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+    const INVALID: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007";
+
+    const VALID: &str = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day04::part1(&Day04::parse(EXAMPLE).unwrap()), Answer::Number(2));
+    }
+
+    #[test]
+    fn part2_invalid_passports() {
+        assert_eq!(Day04::part2(&Day04::parse(INVALID).unwrap()), Answer::Number(0));
+    }
+
+    #[test]
+    fn part2_valid_passports() {
+        assert_eq!(Day04::part2(&Day04::parse(VALID).unwrap()), Answer::Number(4));
+    }
+
+    #[test]
+    fn field_rules() {
+        assert!(is_hgt("60in"));
+        assert!(is_hgt("190cm"));
+        assert!(!is_hgt("190in"));
+        assert!(!is_hgt("190"));
+
+        assert!(is_hcl("#123abc"));
+        assert!(!is_hcl("#123abz"));
+        assert!(!is_hcl("123abc"));
+
+        assert!(is_pid("000000001"));
+        assert!(!is_pid("0123456789"));
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_common::{Answer, Day, ParseError, Solution};
+use itertools::Itertools;
+use std::ops::Range;
+
+#[derive(Debug)]
+struct SeatNumber {
+    row: usize,
+    col: usize
+}
+
+impl SeatNumber {
+    /// Finds the seat of a boarding pass, or the rest of the pass from the first character that
+    /// isn't F, B, L or R.
+    fn new(pos: &str) -> Result<Self, &str> {
+        let mut row_range: Range<usize> = 0..127;
+        let mut col_range: Range<usize> = 0..7;
+
+        for (i, char) in pos.char_indices() {
+            match char {
+                'F' => row_range.end -= (row_range.end - row_range.start).div_ceil(2),
+                'B' => row_range.start += (row_range.end - row_range.start).div_ceil(2),
+
+                'L' => col_range.end -= (col_range.end - col_range.start).div_ceil(2),
+                'R' => col_range.start += (col_range.end - col_range.start).div_ceil(2),
+
+                _ => return Err(&pos[i..])
+            }
+        }
+
+        Ok(Self { row: row_range.start, col: col_range.start })
+    }
+
+    fn get_id(&self) -> usize {
+        self.row * 8 + self.col
+    }
+}
+
+pub struct Day05;
+
+pub const DAY: Day = Day::new::<Day05>(5);
+
+impl Solution for Day05 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        let mut seatids = vec![];
+
+        for line in input.lines() {
+            let seat = SeatNumber::new(line)
+                .map_err(|rest| ParseError::at(input, rest, "expected F, B, L or R"))?;
+            seatids.push(seat.get_id());
+        }
+
+        if seatids.is_empty() {
+            return Err(ParseError::at(input, input, "expected a boarding pass"));
+        }
+
+        Ok(seatids.into_iter().sorted().collect())
+    }
+
+    fn part1(seatids: &Vec<usize>) -> Answer {
+        (*seatids.iter().max().unwrap()).into()
+    }
+
+    fn part2(seatids: &Vec<usize>) -> Answer {
+        let mut myseat = None;
+        let mut last = None;
+
+        for &seatid in seatids {
+            if let Some(last) = last {
+                if last + 2 == seatid {
+                    myseat = Some(seatid - 1);
+                }
+            }
+
+            last = Some(seatid);
+        }
+
+        myseat.expect("there's no empty seat between two taken ones").into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+FBFBBFFRLR
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL";
+
+    #[test]
+    fn seat_numbers() {
+        let seat = SeatNumber::new("FBFBBFFRLR").unwrap();
+        assert_eq!((seat.row, seat.col), (44, 5));
+        assert_eq!(seat.get_id(), 357);
+
+        assert_eq!(SeatNumber::new("BFFFBBFRRR").unwrap().get_id(), 567);
+        assert_eq!(SeatNumber::new("FFFBBBFRRR").unwrap().get_id(), 119);
+        assert_eq!(SeatNumber::new("BBFFBBFRLL").unwrap().get_id(), 820);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day05::part1(&Day05::parse(EXAMPLE).unwrap()), Answer::Number(820));
+    }
+
+    #[test]
+    fn rejects_no_passes() {
+        assert_eq!(Day05::parse("").unwrap_err().message, "expected a boarding pass");
+    }
+
+    #[test]
+    fn part2_finds_the_gap() {
+        assert_eq!(Day05::part2(&vec![3, 4, 6, 7]), Answer::Number(5));
+    }
+}
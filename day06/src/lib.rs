@@ -0,0 +1,119 @@
+use aoc_common::{Answer, Day, ParseError, Solution};
+use std::collections::HashSet;
+
+pub struct GroupAnswers(HashSet<char>);
+
+impl GroupAnswers {
+    fn new() -> Self {
+        GroupAnswers(HashSet::new())
+    }
+
+    fn new_from_str(l: &str) -> Self {
+        let mut hs = Self::new();
+        hs.add_str(l);
+        hs
+    }
+
+
+    fn add_str(&mut self, l: &str) {
+        for char in l.chars() {
+            self.0.insert(char);
+        }
+    }
+
+    fn intersect_str(&self, l: &str) -> Self {
+        let mut hs = Self::new();
+        for char in l.chars() {
+            if self.0.contains(&char) {
+                hs.0.insert(char);
+            }
+        }
+
+        hs
+    }
+}
+
+/// Each group's answers, as the union and the intersection of everyone's answers.
+pub type Groups = Vec<(GroupAnswers, Option<GroupAnswers>)>;
+
+pub struct Day06;
+
+pub const DAY: Day = Day::new::<Day06>(6);
+
+impl Solution for Day06 {
+    type Input = Groups;
+
+    fn parse(input: &str) -> Result<Groups, ParseError> {
+        for line in input.lines() {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_lowercase()) {
+                return Err(ParseError::at(input, &line[i..], "expected a question from a to z"));
+            }
+        }
+
+        Ok(input
+            .lines()
+            .fold(vec![(GroupAnswers::new(), None::<GroupAnswers>)],
+                |mut acc, l| {
+                    // f u n c t i o n a l
+                    match l {
+                        "" => acc.push((GroupAnswers::new(), None)),
+                        l => {
+                            let index = acc.len() - 1;
+                            let b = match &acc[index].1 {
+                                None => GroupAnswers::new_from_str(l),
+                                Some(v) => v.intersect_str(l)
+                            };
+
+                            acc[index].0.add_str(l);
+                            acc[index].1 = Some(b);
+                        }
+                    }
+
+                    acc
+                }))
+    }
+
+    fn part1(groups: &Groups) -> Answer {
+        groups.iter().map(|(a, _)| a.0.len()).sum::<usize>().into()
+    }
+
+    fn part2(groups: &Groups) -> Answer {
+        groups
+            .iter()
+            .map(|(_, b)| b.as_ref().map_or(0, |b| b.0.len()))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day06::part1(&Day06::parse(EXAMPLE).unwrap()), Answer::Number(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day06::part2(&Day06::parse(EXAMPLE).unwrap()), Answer::Number(6));
+    }
+}
@@ -1,5 +1,5 @@
-use std::fs::File;
-use std::io::{self, BufReader, BufRead};
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
@@ -13,9 +13,6 @@ use nom::{
     sequence::terminated,
 
     character::complete::alpha1,
-    character::complete::digit1,
-
-    combinator::map_res,
 
     bytes::complete::tag,
 };
@@ -29,7 +26,7 @@ struct Rule {
 type IRule = Vec<(usize, String)>;
 
 #[derive(Debug)]
-struct Rules {
+pub struct Rules {
     map: HashMap<String, IRule>,
     nmap: HashMap<String, IRule>
 }
@@ -58,9 +55,9 @@ impl Rules {
     fn find_deps(&self, color: &str) -> usize {
         let mut acc = 0;
         let mut done = HashSet::<String>::new();
-        let mut doing = self.map.get(color).unwrap().clone();
+        let mut doing = self.map.get(color).cloned().unwrap_or_default();
 
-        while doing.len() != 0 {
+        while !doing.is_empty() {
             let mut next = Vec::<(usize, String)>::new();
 
             for (_, color) in doing.iter() {
@@ -81,16 +78,12 @@ impl Rules {
     fn count(&self, color: &str) -> usize {
         match self.nmap.get(color) {
             None => 0,
-            Some(held) => held.into_iter()
+            Some(held) => held.iter()
                     .fold(0, |acc, (num, color)| acc + num + num * self.count(color))
         }
     }
 }
 
-fn number(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, |a: &str| a.parse())(input)
-}
-
 fn parse_color(input: &str) -> IResult<&str, String> {
     let (input, (color1, color2)) = separated_pair(alpha1, tag(" "), alpha1)(input)?;
     Ok((input, format!("{} {}", color1, color2)))
@@ -117,21 +110,79 @@ fn parse_rule(input: &str) -> IResult<&str, Rule> {
     Ok((input, Rule { color, holds }))
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file); 
-    let mut rules = Rules::new();
+pub struct Day07;
+
+pub const DAY: Day = Day::new::<Day07>(7);
+
+impl Solution for Day07 {
+    type Input = Rules;
+
+    fn parse(input: &str) -> Result<Rules, ParseError> {
+        let mut rules = Rules::new();
+
+        for line in input.lines() {
+            rules.add_rule(parse_all(input, line, "a bag rule", parse_rule)?);
+        }
+
+        Ok(rules)
+    }
+
+    fn part1(rules: &Rules) -> Answer {
+        rules.find_deps("shiny gold").into()
+    }
 
-    for line in reader.lines() {
-        let (_, rule) = parse_rule(&line?).unwrap();
-        rules.add_rule(rule);
+    fn part2(rules: &Rules) -> Answer {
+        rules.count("shiny gold").into()
     }
+}
 
-    let answer1 = rules.find_deps("shiny gold");
-    let answer2 = rules.count("shiny gold");
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    const NESTED: &str = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.";
+
+    #[test]
+    fn parses_rule() {
+        let line = "muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.";
+        let (_, rule) = parse_rule(line).unwrap();
+
+        assert_eq!(rule.color, "muted yellow");
+        assert_eq!(
+            rule.holds,
+            vec![(2, "shiny gold".to_string()), (9, "faded blue".to_string())]
+        );
+
+        let (_, rule) = parse_rule("faded blue bags contain no other bags.").unwrap();
+        assert!(rule.holds.is_empty());
+    }
 
-    println!("(1) There are {} ways to have a shiny gold bag!", answer1);
-    println!("(2) You need {} bags inside your shiny gold bag!", answer2);
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day07::part1(&Day07::parse(EXAMPLE).unwrap()), Answer::Number(4));
+    }
 
-    Ok(())
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day07::part2(&Day07::parse(EXAMPLE).unwrap()), Answer::Number(32));
+        assert_eq!(Day07::part2(&Day07::parse(NESTED).unwrap()), Answer::Number(126));
+    }
 }
@@ -1,5 +1,5 @@
-use std::fs::File;
-use std::io::{self, BufReader, BufRead};
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
 use std::convert::TryInto;
 
 use nom::{
@@ -9,22 +9,19 @@ use nom::{
     sequence::preceded,
 
     combinator::map,
-    combinator::map_res,
-
-    character::complete::digit1,
 
     bytes::complete::tag,
 };
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Instruction {
     Nop(i32),
     Acc(i32),
     Jmp(i32)
 }
 
-#[derive(Debug)]
-struct Program {
+#[derive(Debug, Clone)]
+pub struct Program {
     acc: i32,
     acc_before: i32,
     ip: usize,
@@ -46,18 +43,17 @@ impl Program {
             match ins {
                 Instruction::Acc(val) => {
                     self.acc_before = self.acc;
-                    self.acc += val
+                    self.acc += val;
+                    self.ip += 1;
                 },
-                Instruction::Nop(_) => {},
+                Instruction::Nop(_) => self.ip += 1,
                 Instruction::Jmp(val) => {
+                    // Jumping before the first instruction is as good as running off the end
                     let ip32: i32 = self.ip.try_into().unwrap();
-                    let newip = ip32 + val - 1;
-                    self.ip = newip.try_into().unwrap()
+                    self.ip = (ip32 + val).try_into().unwrap_or(usize::MAX);
                 }
             }
 
-            self.ip += 1;
-
             Some(*ret)
         }
     }
@@ -76,6 +72,10 @@ impl Program {
         self.acc = 0;
         self.acc_before = 0;
 
+        for (count, _) in &mut self.instructions {
+            *count = 0;
+        }
+
         loop {
             let step = self.step();
 
@@ -84,7 +84,7 @@ impl Program {
                 return false;
             }
             
-            if step == None {
+            if step.is_none() {
                 self.flip(ins);
                 return true;
             }
@@ -92,14 +92,10 @@ impl Program {
     }
 }
 
-fn parse_num(input: &str) -> IResult<&str, i32> {
-    map_res(digit1, |a: &str| a.parse())(input)
-}
-
 fn parse_signed(input: &str) -> IResult<&str, i32> {
     alt((
-        map(preceded(tag("-"), parse_num), |n| -n),
-        preceded(tag("+"), parse_num)))(input)
+        map(preceded(tag("-"), number), |n: i32| -n),
+        preceded(tag("+"), number)))(input)
 }
 
 fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
@@ -110,35 +106,74 @@ fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
         ))(input)
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file); 
+pub struct Day08;
 
-    let mut program = Program::new();
+pub const DAY: Day = Day::new::<Day08>(8);
 
-    for line in reader.lines() {
-        let (_, ins) = parse_instruction(&line?).unwrap();
-        program.instructions.push((0, ins));
-    }
+impl Solution for Day08 {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Program, ParseError> {
+        let mut program = Program::new();
 
-    loop {
-        let step = program.step();
-        if let Some(2) = step {
-            println!("(1) Accumulator is {}", program.acc_before);
-            break;
+        for line in input.lines() {
+            let ins = parse_all(input, line, "an instruction", parse_instruction)?;
+            program.instructions.push((0, ins));
         }
 
-        if step == None {
-            break;
+        Ok(program)
+    }
+
+    fn part1(program: &Program) -> Answer {
+        let mut program = program.clone();
+
+        loop {
+            let step = program.step();
+            if let Some(2) = step {
+                return program.acc_before.into();
+            }
+
+            if step.is_none() {
+                panic!("The program terminated without looping");
+            }
         }
     }
 
-    for i in 0..program.instructions.len() {
-        if program.try_flip(i) {
-            println!("(2) Found successful flip! Accumulator is {}", program.acc);
-            break;
+    fn part2(program: &Program) -> Answer {
+        let mut program = program.clone();
+
+        for i in 0..program.instructions.len() {
+            if program.try_flip(i) {
+                return program.acc.into();
+            }
         }
+
+        panic!("No single flip makes the program terminate")
     }
+}
 
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day08::part1(&Day08::parse(EXAMPLE).unwrap()), Answer::Number(5));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day08::part2(&Day08::parse(EXAMPLE).unwrap()), Answer::Number(8));
+    }
 }
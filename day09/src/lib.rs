@@ -0,0 +1,115 @@
+use aoc_common::{parsers, Answer, Day, ParseError, Solution};
+use std::vec::Vec;
+
+fn find_invalid(nums: &[usize], preamble: usize) -> usize {
+    for i in preamble..nums.len() {
+        let mut found = false;
+        for j in i-preamble..i {
+            for k in j+1..i {
+                if nums[j] + nums[k] == nums[i] {
+                    found = true;
+                }
+            }
+        }
+
+        if !found {
+            return nums[i];
+        }
+    }
+
+    panic!("Every number is the sum of two of the previous {}", preamble)
+}
+
+fn find_weakness(nums: &[usize], invalid: usize) -> usize {
+    for i in 0..nums.len() {
+        let mut sum = 0;
+
+        let mut min = None;
+        let mut max = None;
+
+        let mut j = i;
+        while sum < invalid {
+            sum += nums[j];
+
+            match min {
+                None => min = Some(nums[j]),
+                Some(k) => if nums[j] < k { min = Some(nums[j]) }
+            }
+
+            match max {
+                None => max = Some(nums[j]),
+                Some(k) => if nums[j] > k { max = Some(nums[j]) }
+            }
+
+            if sum >= invalid {
+                break;
+            }
+
+            j += 1;
+        }
+
+        if sum == invalid {
+            return max.unwrap() + min.unwrap();
+        }
+    }
+
+    panic!("No contiguous range sums to {}", invalid)
+}
+
+pub struct Day09;
+
+pub const DAY: Day = Day::new::<Day09>(9);
+
+impl Solution for Day09 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        parsers::lines(input, "a number")
+    }
+
+    fn part1(nums: &Vec<usize>) -> Answer {
+        find_invalid(nums, 25).into()
+    }
+
+    fn part2(nums: &Vec<usize>) -> Answer {
+        find_weakness(nums, find_invalid(nums, 25)).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576";
+
+    // The example uses a preamble of 5 rather than 25, so it can't go through the parts.
+    #[test]
+    fn finds_invalid_number() {
+        assert_eq!(find_invalid(&Day09::parse(EXAMPLE).unwrap(), 5), 127);
+    }
+
+    #[test]
+    fn finds_weakness() {
+        assert_eq!(find_weakness(&Day09::parse(EXAMPLE).unwrap(), 127), 62);
+    }
+}
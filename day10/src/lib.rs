@@ -0,0 +1,163 @@
+use aoc_common::{parsers, Answer, Day, ParseError, Solution};
+use std::collections::BinaryHeap;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Memoise {
+    map: HashMap<u64, u64>
+}
+
+impl Memoise {
+    fn new() -> Self {
+        Self { map: HashMap::new() }
+    }
+
+    fn find(&mut self, nums: &Vec<u64>, num: u64) -> u64 {
+        if num == 0 {
+            return 1;
+        }
+
+        match self.map.get(&num) {
+            Some(count) => *count,
+            None => {
+                // We can optimise the hell out of this because the vector is sorted
+                // We just need to count the number of ways to get here
+                let mut count = 0;
+
+                // 0, 2, 4, 7
+                // 0, 1, 2, 4, 7
+                //
+                // find(2) = 2
+                // find(4) = 2
+                // find(7) = 2
+
+                for i in nums.iter() {
+                    if *i >= num {
+                        break;
+                    }
+
+                    if num - i > 0 && num - i <= 3 {
+                        count += self.find(nums, *i);
+                    }
+                }
+
+                self.map.insert(num, count);
+
+                count
+            }
+        }
+    }
+}
+
+pub struct Day10;
+
+pub const DAY: Day = Day::new::<Day10>(10);
+
+impl Solution for Day10 {
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+        // The answer for part 2 is easily in the trillions, so u32 won't cut it!
+        let mut nums: BinaryHeap<u64> = parsers::lines(input, "a joltage")?.into();
+
+        nums.push(0);
+        let largest = nums.peek().unwrap() + 3;
+        nums.push(largest);
+
+        Ok(nums.into_sorted_vec())
+    }
+
+    fn part1(nums: &Vec<u64>) -> Answer {
+        let mut last = 0;
+        let mut one_diff = 0;
+        let mut three_diff = 0;
+
+        for i in nums.iter() {
+            if i - last == 1 {
+                one_diff += 1;
+            } else if i - last == 3 {
+                three_diff += 1;
+            }
+
+            last = *i;
+        }
+
+        (one_diff * three_diff).into()
+    }
+
+    fn part2(nums: &Vec<u64>) -> Answer {
+        let largest = *nums.last().unwrap();
+
+        // Part 2:
+        // This problem really lends itself to memoisation!
+        // If we start from the top...
+        // We can just peek ahead and find all the adapters we can connect to, recursively!
+        // We just need to memorize a map of starting => count
+
+        let mut memoise = Memoise::new();
+        memoise.find(nums, largest).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL: &str = "\
+16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+
+    const LARGE: &str = "\
+28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day10::part1(&Day10::parse(SMALL).unwrap()), Answer::Number(7 * 5));
+        assert_eq!(Day10::part1(&Day10::parse(LARGE).unwrap()), Answer::Number(22 * 10));
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(Day10::part2(&Day10::parse(SMALL).unwrap()), Answer::Number(8));
+        assert_eq!(Day10::part2(&Day10::parse(LARGE).unwrap()), Answer::Number(19208));
+    }
+}
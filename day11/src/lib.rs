@@ -0,0 +1,165 @@
+use aoc_common::grid::DIRECTIONS8;
+use aoc_common::progress;
+use aoc_common::{Answer, Day, Grid, ParseError, Solution};
+use std::fmt;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Position {
+    Floor,
+    Seat(bool)
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", match self {
+            Position::Seat(true) => "#",
+            Position::Seat(false) => "L",
+            Position::Floor => "."
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Seats {
+    seats: Grid<Position>,
+}
+
+impl fmt::Display for Seats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.seats)
+    }
+}
+
+impl Seats {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let seats = Grid::parse(input, |c| match c {
+            '.' => Some(Position::Floor),
+            '#' => Some(Position::Seat(true)),
+            'L' => Some(Position::Seat(false)),
+            _ => None
+        })?;
+
+        Ok(Self { seats })
+    }
+
+    fn get_adjacent(&self, x: usize, y: usize) -> usize {
+        self.seats
+            .neighbours8(x, y)
+            .filter(|&&p| p == Position::Seat(true))
+            .count()
+    }
+
+    fn get_adjacent2(&self, x: usize, y: usize) -> usize {
+        DIRECTIONS8
+            .iter()
+            .filter(|&&dir| {
+                self.seats.first_seen(x, y, dir, |&p| p == Position::Floor)
+                    == Some(&Position::Seat(true))
+            })
+            .count()
+    }
+
+    // returns true if it's changed
+    fn step_with(&mut self, tolerance: usize, adjacent: fn(&Self, usize, usize) -> usize) -> bool {
+        let mut changed = false;
+
+        let new_seats = Grid::from_fn(self.seats.width(), self.seats.height(), |x, y| {
+            match self.seats[(x, y)] {
+                Position::Seat(true) if adjacent(self, x, y) >= tolerance => {
+                    changed = true;
+                    Position::Seat(false)
+                },
+                Position::Seat(false) if adjacent(self, x, y) == 0 => {
+                    changed = true;
+                    Position::Seat(true)
+                },
+                p => p
+            }
+        });
+
+        self.seats = new_seats;
+
+        changed
+    }
+
+    fn step(&mut self) -> bool {
+        self.step_with(4, Self::get_adjacent)
+    }
+
+    fn step2(&mut self) -> bool {
+        self.step_with(5, Self::get_adjacent2)
+    }
+
+    fn count_occupied(&self) -> usize {
+        self.seats.cells()
+            .filter(|&&seat| seat == Position::Seat(true))
+            .count()
+    }
+}
+
+pub struct Day11;
+
+pub const DAY: Day = Day::new::<Day11>(11);
+
+impl Solution for Day11 {
+    type Input = Seats;
+
+    fn parse(input: &str) -> Result<Seats, ParseError> {
+        Seats::new(input)
+    }
+
+    fn part1(seats: &Seats) -> Answer {
+        let mut seats = seats.clone();
+
+        for generation in 1.. {
+            if !seats.step() {
+                break;
+            }
+
+            progress::report(generation, None);
+        }
+
+        seats.count_occupied().into()
+    }
+
+    fn part2(seats: &Seats) -> Answer {
+        let mut seats = seats.clone();
+
+        for generation in 1.. {
+            if !seats.step2() {
+                break;
+            }
+
+            progress::report(generation, None);
+        }
+
+        seats.count_occupied().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day11::part1(&Day11::parse(EXAMPLE).unwrap()), Answer::Number(37));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day11::part2(&Day11::parse(EXAMPLE).unwrap()), Answer::Number(26));
+    }
+}
@@ -1,19 +1,16 @@
-use std::fs::File;
-use std::io::{self, BufReader, BufRead};
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
 use nom::{
-    IResult,
-
     branch::alt,
     bytes::complete::tag,
-    character::complete::digit1,
 
     combinator::map,
-    combinator::map_res,
     sequence::preceded,
+    IResult,
 };
 
 #[derive(Debug, Clone, Copy)]
-enum Route {
+pub enum Route {
     Forward(i32),
     Right(i32),
     Left(i32),
@@ -107,33 +104,62 @@ fn step2(waypoint: Waypoint, route: Route) -> Waypoint {
     }
 }
 
-fn parse_number<'a>(input: &str) -> IResult<&str, i32> {
-    map_res(digit1, |a: &str| a.parse())(input)
+fn parse_route(input: &str) -> IResult<&str, Route> {
+    alt((
+        map(preceded(tag("F"), number), Route::Forward),
+        map(preceded(tag("R"), number), Route::Right),
+        map(preceded(tag("L"), number), Route::Left),
+        map(preceded(tag("N"), number), Route::North),
+        map(preceded(tag("W"), number), Route::West),
+        map(preceded(tag("S"), number), Route::South),
+        map(preceded(tag("E"), number), Route::East)))(input)
 }
 
-fn parse_route(input: &str) -> Route {
-    alt((
-        map(preceded(tag("F"), parse_number), Route::Forward),
-        map(preceded(tag("R"), parse_number), Route::Right),
-        map(preceded(tag("L"), parse_number), Route::Left),
-        map(preceded(tag("N"), parse_number), Route::North),
-        map(preceded(tag("W"), parse_number), Route::West),
-        map(preceded(tag("S"), parse_number), Route::South),
-        map(preceded(tag("E"), parse_number), Route::East)))(input).unwrap().1
+pub struct Day12;
+
+pub const DAY: Day = Day::new::<Day12>(12);
+
+impl Solution for Day12 {
+    type Input = Vec<Route>;
+
+    fn parse(input: &str) -> Result<Vec<Route>, ParseError> {
+        input
+            .lines()
+            .map(|line| parse_all(input, line, "a navigation instruction", parse_route))
+            .collect()
+    }
+
+    fn part1(routes: &Vec<Route>) -> Answer {
+        let pos = routes.iter().copied().fold(Pos { x: 0, y: 0, facing: 0 }, step1);
+
+        (pos.x.abs() + pos.y.abs()).into()
+    }
+
+    fn part2(routes: &Vec<Route>) -> Answer {
+        let waypoint = routes.iter().copied().fold(Waypoint::new(), step2);
+
+        (waypoint.ship_x.abs() + waypoint.ship_y.abs()).into()
+    }
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let (answer1, answer2) = reader
-        .lines()
-        .filter_map(|l| l.ok())
-        .map(|a| parse_route(&a))
-        .fold((Pos { x: 0, y: 0, facing: 0 }, Waypoint::new()), |(a, b), r| (step1(a,r), step2(b, r)));
+    const EXAMPLE: &str = "\
+F10
+N3
+F7
+R90
+F11";
 
-    println!("(1) Distance is {}", answer1.x.abs() + answer1.y.abs());
-    println!("(2) Distance is {}", answer2.ship_x.abs() + answer2.ship_y.abs());
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day12::part1(&Day12::parse(EXAMPLE).unwrap()), Answer::Number(25));
+    }
 
-    Ok(())
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day12::part2(&Day12::parse(EXAMPLE).unwrap()), Answer::Number(286));
+    }
 }
@@ -0,0 +1,150 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    IResult,
+
+    bytes::complete::tag,
+
+    character::complete::line_ending,
+
+    combinator::map,
+    combinator::value,
+
+    multi::separated_list1,
+
+    sequence::separated_pair,
+
+    branch::alt
+};
+
+#[derive(Debug, Clone)]
+pub enum Bus {
+    X,
+    Time(usize)
+}
+
+#[derive(Debug)]
+pub struct Timetable {
+    time: usize,
+    busses: Vec<Bus>
+}
+
+fn parse_busses(input: &str) -> IResult<&str, Vec<Bus>> {
+    separated_list1(tag(","), alt((value(Bus::X, tag("x")), map(number, Bus::Time))))(input)
+}
+
+fn parse_timetable(input: &str) -> IResult<&str, Timetable> {
+    let (input, (time, busses)) = separated_pair(number, line_ending, parse_busses)(input)?;
+
+    Ok((input, Timetable { time, busses }))
+}
+
+pub struct Day13;
+
+pub const DAY: Day = Day::new::<Day13>(13);
+
+impl Solution for Day13 {
+    type Input = Timetable;
+
+    fn parse(input: &str) -> Result<Timetable, ParseError> {
+        let timetable = parse_all(input, input, "a timetable", parse_timetable)?;
+
+        // Parsing got this far, so the busses are on the second line, one per comma
+        let line = input.lines().nth(1).unwrap_or("");
+        for (bus, token) in timetable.busses.iter().zip(line.split(',')) {
+            if let Bus::Time(0) = bus {
+                return Err(ParseError::at(input, token, "expected a bus id of at least 1"));
+            }
+        }
+
+        if timetable.busses.iter().all(|bus| matches!(bus, Bus::X)) {
+            return Err(ParseError::at(input, line, "expected at least one bus"));
+        }
+
+        Ok(timetable)
+    }
+
+    fn part1(timetable: &Timetable) -> Answer {
+        let mut min = None;
+        let mut id = None;
+
+        for i in &timetable.busses {
+            if let Bus::Time(x) = i {
+                let q: usize = (timetable.time / x) * x + x;
+
+                match min {
+                    None => {
+                        min = Some(q);
+                        id = Some(x)
+                    },
+                    Some(i) => if q < i {
+                        min = Some(q);
+                        id = Some(x);
+                    }
+                }
+            }
+        }
+
+        ((min.unwrap() - timetable.time) * id.unwrap()).into()
+    }
+
+    fn part2(timetable: &Timetable) -> Answer {
+        // Some observations for part 2:
+        // The numbers are all prime, so the least common multiple of any of them is just their
+        // product. Once we've found a time that works for the first n busses, adding that product
+        // keeps it working for all of them, so we can sieve one bus at a time.
+        let mut time = 0;
+        let mut step = 1;
+
+        for (i, bus) in timetable.busses.iter().enumerate() {
+            if let Bus::Time(x) = bus {
+                while (time + i) % x != 0 {
+                    time += step;
+                }
+
+                step *= x;
+            }
+        }
+
+        time.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+939
+7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day13::part1(&Day13::parse(EXAMPLE).unwrap()), Answer::Number(295));
+    }
+
+    #[test]
+    fn rejects_bus_zero() {
+        let err = Day13::parse("939\n7,0,x").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.message, "expected a bus id of at least 1");
+    }
+
+    #[test]
+    fn rejects_no_busses() {
+        let err = Day13::parse("939\nx,x").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected at least one bus");
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(Day13::part2(&Day13::parse(EXAMPLE).unwrap()), Answer::Number(1068781));
+        assert_eq!(Day13::part2(&Day13::parse("0\n17,x,13,19").unwrap()), Answer::Number(3417));
+        assert_eq!(Day13::part2(&Day13::parse("0\n67,7,59,61").unwrap()), Answer::Number(754018));
+        assert_eq!(
+            Day13::part2(&Day13::parse("0\n1789,37,47,1889").unwrap()),
+            Answer::Number(1202161486)
+        );
+    }
+}
@@ -1,23 +1,20 @@
-use std::fs::File;
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
 use std::collections::HashMap;
-use std::io::{self, BufRead, BufReader};
 use std::fmt;
 
 use nom::{
     IResult,
 
-    combinator::map_res,
     combinator::map,
 
     bytes::complete::tag,
+    bytes::complete::take_while_m_n,
 
     branch::alt,
 
     sequence::preceded,
     sequence::terminated,
-
-    character::complete::digit1,
-    character::complete::alphanumeric1,
 };
 
 #[derive(Clone, Copy)]
@@ -38,7 +35,7 @@ impl fmt::Debug for OneBit {
 }
 
 #[derive(Debug, Clone)]
-struct BitMask {
+pub struct BitMask {
     mask: [OneBit; 36]
 }
 
@@ -112,7 +109,7 @@ impl BitMask {
 }
 
 #[derive(Debug, Clone)]
-enum Instruction {
+pub enum Instruction {
     Mask(BitMask),
     Set(u64, u64)
 }
@@ -133,7 +130,8 @@ impl Program {
         match &self.instructions[self.ip] {
             Instruction::Mask(mask) => self.mask = Some(mask.clone()),
             Instruction::Set(place, number) => {
-                let masked = self.mask.as_ref().unwrap().apply(*number);
+                let mask = self.mask.as_ref().expect("parse checks there's a mask");
+                let masked = mask.apply(*number);
                 self.memory.insert(*place, from_bits(&masked));
             }
         }
@@ -147,7 +145,8 @@ impl Program {
         match &self.instructions[self.ip] {
             Instruction::Mask(mask) => self.mask = Some(mask.clone()),
             Instruction::Set(place, number) => {
-                let masked = self.mask.as_ref().unwrap().applications(*place);
+                let mask = self.mask.as_ref().expect("parse checks there's a mask");
+                let masked = mask.applications(*place);
 
                 for i in masked {
                     self.memory.insert(i, *number);
@@ -169,23 +168,21 @@ fn from_bits(bits: &[OneBit; 36]) -> u64 {
             OneBit::One => 1,
             _ => panic!("Invalid bit")
         };
-        ret = ret | (bit << (35 - i));
+        ret |= bit << (35 - i);
     }
 
     ret
 }
 
-fn parse_number(input: &str) -> IResult<&str, u64> {
-    map_res(digit1, |a: &str| a.parse())(input)
-}
-
 fn parse_mask(input: &str) -> IResult<&str, Instruction> {
-    map(map(preceded(tag("mask = "), alphanumeric1), BitMask::new), Instruction::Mask)(input)
+    let bits = take_while_m_n(36, 36, |c| matches!(c, '0' | '1' | 'X'));
+
+    map(map(preceded(tag("mask = "), bits), BitMask::new), Instruction::Mask)(input)
 }
 
 fn parse_mem(input: &str) -> IResult<&str, Instruction> {
-    let (input, location) = terminated(preceded(tag("mem["), parse_number), tag("] = "))(input)?;
-    let (input, value) = parse_number(input)?;
+    let (input, location) = terminated(preceded(tag("mem["), number), tag("] = "))(input)?;
+    let (input, value) = number(input)?;
 
     Ok((input, Instruction::Set(location, value)))
 }
@@ -194,36 +191,77 @@ fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
     alt((parse_mask, parse_mem))(input)
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
-    let mut instructions = vec![];
+pub struct Day14;
+
+pub const DAY: Day = Day::new::<Day14>(14);
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        let mut instructions = vec![];
+
+        for line in input.lines() {
+            let instruction =
+                parse_all(input, line, "a mask or a memory write", parse_instruction)?;
+
+            if instructions.is_empty() && matches!(instruction, Instruction::Set(..)) {
+                return Err(ParseError::at(input, line, "expected a mask before any memory write"));
+            }
+
+            instructions.push(instruction);
+        }
 
-    for l in reader.lines() {
-        let (_, ins) = parse_instruction(&l?).unwrap();
-        instructions.push(ins);
+        Ok(instructions)
     }
 
-    let instructions2 = instructions.clone();
+    fn part1(instructions: &Vec<Instruction>) -> Answer {
+        let mut program = Program::new(instructions.clone());
 
-    let mut program = Program::new(instructions);
+        while program.step() {}
 
-    while program.step() {}
+        program.memory.values().sum::<u64>().into()
+    }
 
-    let answer1 = program.memory.iter()
-        .map(|(_,v)| v)
-        .fold(0, |acc, val| acc + val);
+    fn part2(instructions: &Vec<Instruction>) -> Answer {
+        let mut program = Program::new(instructions.clone());
 
-    let mut program2 = Program::new(instructions2);
+        while program.step2() {}
 
-    while program2.step2() {}
+        program.memory.values().sum::<u64>().into()
+    }
+}
 
-    let answer2 = program2.memory.iter()
-        .map(|(_,v)| v)
-        .fold(0, |acc, val| acc + val);
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+    const EXAMPLE2: &str = "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day14::part1(&Day14::parse(EXAMPLE1).unwrap()), Answer::Number(165));
+    }
 
-    println!("(1) Sum of values in memory is {}", answer1);
-    println!("(1) Sum of values in memory is {}", answer2);
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day14::part2(&Day14::parse(EXAMPLE2).unwrap()), Answer::Number(208));
+    }
 
-    Ok(())
+    #[test]
+    fn rejects_writes_before_a_mask() {
+        let input = "mem[8] = 11\nmask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X";
+        let err = Day14::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
 }
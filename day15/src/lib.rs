@@ -0,0 +1,105 @@
+use aoc_common::progress;
+use aoc_common::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct State {
+    iteration: usize,
+    last_spoken: HashMap<usize, usize>,
+    numbers: Vec<usize>,
+    prev: Option<usize>
+}
+
+impl State {
+    fn new(numbers: Vec<usize>) -> Self {
+        Self { numbers, iteration: 0, last_spoken: HashMap::new(), prev: None }
+    }
+
+    fn step(&mut self) -> usize {
+        if self.iteration < self.numbers.len() {
+            if let Some(i) = self.prev {
+                self.last_spoken.insert(i, self.iteration);
+            }
+
+            self.prev = Some(self.numbers[self.iteration]);
+            self.iteration += 1;
+            self.numbers[self.iteration - 1]
+        } else {
+            match self.last_spoken.insert(self.prev.unwrap(), self.iteration) {
+                None => {
+                    self.prev = Some(0);
+                    self.iteration += 1;
+                    0
+                },
+                Some(i) => {
+                    self.prev = Some(self.iteration - i);
+                    self.iteration += 1;
+                    self.prev.unwrap()
+                }
+            }
+        }
+    }
+}
+
+fn nth_spoken(numbers: &[usize], n: usize) -> usize {
+    let mut state = State::new(numbers.to_vec());
+
+    for i in 1..n {
+        if i % (1 << 16) == 0 {
+            progress::report(i as u64, Some(n as u64));
+        }
+
+        state.step();
+    }
+
+    state.step()
+}
+
+pub struct Day15;
+
+pub const DAY: Day = Day::new::<Day15>(15);
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Vec<usize>, ParseError> {
+        input
+            .trim()
+            .split(',')
+            .map(|n| {
+                n.parse()
+                    .map_err(|_| ParseError::at(input, n, "expected a starting number"))
+            })
+            .collect()
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Answer {
+        nth_spoken(numbers, 2020).into()
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Answer {
+        nth_spoken(numbers, 30000000).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(Day15::part1(&Day15::parse("0,3,6").unwrap()), Answer::Number(436));
+        assert_eq!(Day15::part1(&Day15::parse("1,3,2").unwrap()), Answer::Number(1));
+        assert_eq!(Day15::part1(&Day15::parse("2,1,3").unwrap()), Answer::Number(10));
+        assert_eq!(Day15::part1(&Day15::parse("1,2,3").unwrap()), Answer::Number(27));
+        assert_eq!(Day15::part1(&Day15::parse("2,3,1").unwrap()), Answer::Number(78));
+        assert_eq!(Day15::part1(&Day15::parse("3,2,1").unwrap()), Answer::Number(438));
+        assert_eq!(Day15::part1(&Day15::parse("3,1,2").unwrap()), Answer::Number(1836));
+    }
+
+    #[test]
+    fn first_turns() {
+        let spoken: Vec<_> = (1..=10).map(|n| nth_spoken(&[0, 3, 6], n)).collect();
+        assert_eq!(spoken, vec![0, 3, 6, 0, 3, 3, 1, 0, 4, 0]);
+    }
+}
@@ -1,17 +1,16 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
 use nom::{
-    bytes::complete::tag, bytes::complete::take_until, character::complete::digit1,
-    character::complete::line_ending, combinator::map_res, multi::many1, multi::separated_list1,
+    bytes::complete::tag, bytes::complete::take_until,
+    character::complete::line_ending, multi::many1, multi::separated_list1,
     sequence::preceded, sequence::separated_pair, sequence::terminated, IResult,
 };
-use std::collections::HashMap;
-use std::fs::read_to_string;
-use std::io;
 use std::ops::Range;
 
 type Ticket = Vec<usize>;
 
-#[derive(Debug)]
-struct Rule {
+#[derive(Debug, Clone)]
+pub struct Rule {
     name: String,
     range1: Range<usize>,
     range2: Range<usize>,
@@ -24,12 +23,11 @@ impl Rule {
     }
 }
 
-#[derive(Debug)]
-struct Notes {
+#[derive(Debug, Clone)]
+pub struct Notes {
     rules: Vec<Rule>,
     yours: Ticket,
     nearby: Vec<Ticket>,
-    fields: HashMap<usize, String>,
 }
 
 impl Notes {
@@ -39,7 +37,7 @@ impl Notes {
         // Then, over every rule. If none of the rules are valid, we add the field to the return
         // value. If at least one is, we short-circuit and just skip ahead! Shouldn't be terribly
         // hard to do any of this.
-        self.nearby.iter().fold(0, |s, t| s + self.check_ticket(t).iter().fold(0, |s, t| s + t))
+        self.nearby.iter().fold(0, |s, t| s + self.check_ticket(t).iter().sum::<usize>())
     }
 
     fn check_ticket(&self, ticket: &Ticket) -> Vec<usize> {
@@ -59,7 +57,7 @@ impl Notes {
     fn filter_bad_tickets(&mut self) {
         let mut i = 0;
         while i < self.nearby.len() {
-            if self.check_ticket(&self.nearby[i]).len() != 0 {
+            if !self.check_ticket(&self.nearby[i]).is_empty() {
                 self.nearby.remove(i);
                 continue;
             }
@@ -80,7 +78,7 @@ impl Notes {
             let mut i = 0;
 
             while i < possible_fields.len() {
-                if !done.iter().any(|&j| j == i) && possible_fields[i].len() == 1 {
+                if !done.contains(&i) && possible_fields[i].len() == 1 {
                     done.push(i);
                     let needle = possible_fields[i][0];
                     
@@ -134,10 +132,6 @@ impl Notes {
     }
 }
 
-fn number(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, |a: &str| a.parse())(input)
-}
-
 fn range(input: &str) -> IResult<&str, Range<usize>> {
     let (input, (a, b)) = separated_pair(number, tag("-"), number)(input)?;
 
@@ -188,24 +182,83 @@ fn notes(input: &str) -> IResult<&str, Notes> {
             rules,
             nearby,
             yours,
-            fields: HashMap::new(),
         },
     ))
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = read_to_string("input.txt")?;
+pub struct Day16;
+
+pub const DAY: Day = Day::new::<Day16>(16);
+
+impl Solution for Day16 {
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Notes, ParseError> {
+        parse_all(input, input, "ticket notes", notes)
+    }
+
+    fn part1(notes: &Notes) -> Answer {
+        notes.check_tickets().into()
+    }
+
+    fn part2(notes: &Notes) -> Answer {
+        let mut notes = notes.clone();
+
+        notes.filter_bad_tickets();
+        notes.my_fields().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
 
-    let (_, mut input) = notes(&file).unwrap();
+your ticket:
+7,1,14
 
-    println!(
-        "(1) The ticket scanning error rate is {}",
-        input.check_tickets()
-    );
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
 
-    input.filter_bad_tickets();
+    const EXAMPLE2: &str = "\
+class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
 
-    println!("(2) The answer is {}", input.my_fields());
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day16::part1(&Day16::parse(EXAMPLE1).unwrap()), Answer::Number(71));
+    }
 
-    Ok(())
+    #[test]
+    fn filters_bad_tickets() {
+        let mut notes = Day16::parse(EXAMPLE1).unwrap();
+        notes.filter_bad_tickets();
+
+        assert_eq!(notes.nearby, vec![vec![7, 3, 47]]);
+    }
+
+    // The example has no departure fields, so check the matching itself.
+    #[test]
+    fn matches_fields() {
+        let mut notes = Day16::parse(EXAMPLE2).unwrap();
+        notes.filter_bad_tickets();
+
+        assert_eq!(notes.match_fields(), vec!["row", "class", "seat"]);
+    }
 }
@@ -1,7 +1,5 @@
+use aoc_common::{Answer, Day, Grid, ParseError, Solution};
 use std::collections::HashSet;
-use std::convert::TryInto;
-use std::fs::File;
-use std::io::{self, BufRead, BufReader};
 
 #[derive(Debug, Hash, Eq, PartialEq, Clone)]
 struct Coordinate(i64, i64, i64, i64);
@@ -39,27 +37,26 @@ impl Coordinate {
 }
 
 #[derive(Debug, Clone)]
-struct Dimension {
+pub struct Dimension {
     map: HashSet<Coordinate>,
 }
 
 impl Dimension {
-    fn new(input: BufReader<File>) -> Self {
-        let mut map = HashSet::new();
-
-        for (i, line) in input.lines().enumerate() {
-            let line = line.unwrap();
-
-            for (j, char) in line.chars().enumerate() {
-                if char == '#' {
-                    let y = i.try_into().unwrap();
-                    let x = j.try_into().unwrap();
-                    map.insert(Coordinate(x, y, 0, 0));
-                }
-            }
-        }
-
-        Self { map }
+    fn new(input: &str) -> Result<Self, ParseError> {
+        // The input is a single slice of the dimension, at z = 0 and w = 0
+        let slice = Grid::parse(input, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        let map = slice
+            .iter()
+            .filter(|(_, &active)| active)
+            .map(|((x, y), _)| Coordinate(x as i64, y as i64, 0, 0))
+            .collect();
+
+        Ok(Self { map })
     }
 
     fn get_state(&self, coord: &Coordinate) -> i64 {
@@ -102,7 +99,7 @@ impl Dimension {
                 .iter()
                 .filter(|&c| *c != coord)
                 .map(|c| self.get_state(c))
-                .fold(0, |a, s| a + s);
+                .sum::<i64>();
 
             if self.get_state(&coord) == 0 && activeneighbours == 3
                 || self.get_state(&coord) == 1 && (activeneighbours == 2 || activeneighbours == 3)
@@ -123,7 +120,7 @@ impl Dimension {
                 .iter()
                 .filter(|&c| *c != coord)
                 .map(|c| self.get_state(c))
-                .fold(0, |a, s| a + s);
+                .sum::<i64>();
 
             if self.get_state(&coord) == 0 && activeneighbours == 3
                 || self.get_state(&coord) == 1 && (activeneighbours == 2 || activeneighbours == 3)
@@ -134,23 +131,56 @@ impl Dimension {
 
         self.map = ret;
     }
-
 }
 
-fn main() -> Result<(), io::Error> {
-    let file = File::open("input.txt")?;
-    let reader = BufReader::new(file);
+pub struct Day17;
+
+pub const DAY: Day = Day::new::<Day17>(17);
 
-    let mut dimension1 = Dimension::new(reader);
-    let mut dimension2 = dimension1.clone();
+impl Solution for Day17 {
+    type Input = Dimension;
 
-    for _ in 0..6 {
-        dimension1.step3d();
-        dimension2.step4d();
+    fn parse(input: &str) -> Result<Dimension, ParseError> {
+        Dimension::new(input)
     }
 
-    println!("(1) There are {} cubes", dimension1.map.len());
-    println!("(2) There are {} cubes", dimension2.map.len());
+    fn part1(dimension: &Dimension) -> Answer {
+        let mut dimension = dimension.clone();
+
+        for _ in 0..6 {
+            dimension.step3d();
+        }
+
+        dimension.map.len().into()
+    }
 
-    Ok(())
+    fn part2(dimension: &Dimension) -> Answer {
+        let mut dimension = dimension.clone();
+
+        for _ in 0..6 {
+            dimension.step4d();
+        }
+
+        dimension.map.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.#.
+..#
+###";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day17::part1(&Day17::parse(EXAMPLE).unwrap()), Answer::Number(112));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day17::part2(&Day17::parse(EXAMPLE).unwrap()), Answer::Number(848));
+    }
 }
@@ -0,0 +1,154 @@
+use aoc_common::parsers::{self, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    branch::alt, bytes::complete::tag, combinator::map, combinator::value, multi::fold_many0,
+    sequence::pair, sequence::preceded, sequence::terminated, sequence::tuple, IResult,
+};
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    Multiply,
+    Add,
+}
+
+#[derive(Debug, Clone)]
+pub enum Expression {
+    Value(usize),
+    Expression(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    fn eval(&self) -> usize {
+        match self {
+            Expression::Value(i) => *i,
+            Expression::Expression(a, o, b) => match o {
+                Operator::Add => a.eval() + b.eval(),
+                Operator::Multiply => a.eval() * b.eval(),
+            },
+        }
+    }
+}
+
+fn number(input: &str) -> IResult<&str, Expression> {
+    map(parsers::number, Expression::Value)(input)
+}
+
+fn operator(input: &str) -> IResult<&str, Operator> {
+    alt((
+        value(Operator::Add, tag("+")),
+        value(Operator::Multiply, tag("*")),
+    ))(input)
+}
+
+fn single_expr1(input: &str) -> IResult<&str, Expression> {
+    let paren_expr = preceded(tag("("), terminated(expression1, tag(")")));
+    alt((paren_expr, number))(input)
+}
+
+fn single_expr2(input: &str) -> IResult<&str, Expression> {
+    let paren_expr = preceded(tag("("), terminated(expression2, tag(")")));
+    alt((paren_expr, number))(input)
+}
+
+fn expression1(mut input: &str) -> IResult<&str, Expression> {
+    let (ninput, mut expr) = single_expr1(input)?;
+    input = ninput;
+
+    loop {
+        match tuple((
+            preceded(tag(" "), terminated(operator, tag(" "))),
+            single_expr1,
+        ))(input)
+        {
+            Err(_) => break,
+            Ok((ninput, (operator, nexpr))) => {
+                input = ninput;
+                expr = Expression::Expression(Box::new(expr), operator, Box::new(nexpr));
+            }
+        }
+    }
+
+    Ok((input, expr))
+}
+
+fn add(input: &str) -> IResult<&str, Expression> {
+    let (i, init) = single_expr2(input)?;
+
+    fold_many0(pair(tag(" + "), single_expr2), init, |acc, (_, val)| {
+        Expression::Expression(Box::new(acc), Operator::Add, Box::new(val))
+    })(i)
+}
+
+fn expression2(input: &str) -> IResult<&str, Expression> {
+    let (i, init) = add(input)?;
+
+    fold_many0(pair(tag(" * "), add), init, |acc, (_, val)| {
+        Expression::Expression(Box::new(acc), Operator::Multiply, Box::new(val))
+    })(i)
+}
+
+pub struct Day18;
+
+pub const DAY: Day = Day::new::<Day18>(18);
+
+impl Solution for Day18 {
+    /// Every line, parsed with the precedence rules of both parts.
+    type Input = Vec<(Expression, Expression)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut exprs = vec![];
+
+        for line in input.lines() {
+            let expr1 = parse_all(input, line, "an expression", expression1)?;
+            let expr2 = parse_all(input, line, "an expression", expression2)?;
+            exprs.push((expr1, expr2));
+        }
+
+        Ok(exprs)
+    }
+
+    fn part1(exprs: &Self::Input) -> Answer {
+        exprs.iter().map(|(expr, _)| expr.eval()).sum::<usize>().into()
+    }
+
+    fn part2(exprs: &Self::Input) -> Answer {
+        exprs.iter().map(|(_, expr)| expr.eval()).sum::<usize>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLES: &[(&str, usize, usize)] = &[
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        ("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", 13632, 23340),
+    ];
+
+    #[test]
+    fn left_to_right() {
+        for &(expr, value, _) in EXAMPLES {
+            assert_eq!(expression1(expr).unwrap().1.eval(), value, "{}", expr);
+        }
+    }
+
+    #[test]
+    fn addition_first() {
+        for &(expr, _, value) in EXAMPLES {
+            assert_eq!(expression2(expr).unwrap().1.eval(), value, "{}", expr);
+        }
+    }
+
+    #[test]
+    fn parts_sum_every_line() {
+        let input = EXAMPLES.iter().map(|(expr, _, _)| *expr).collect::<Vec<_>>().join("\n");
+        let exprs = Day18::parse(&input).unwrap();
+
+        assert_eq!(Day18::part1(&exprs), Answer::Number(71 + 51 + 26 + 437 + 12240 + 13632));
+        assert_eq!(Day18::part2(&exprs), Answer::Number(231 + 51 + 46 + 1445 + 669060 + 23340));
+    }
+}
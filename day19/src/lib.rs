@@ -0,0 +1,273 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::{
+    branch::alt, bytes::complete::tag, combinator::map, combinator::value, sequence::delimited,
+    IResult,
+};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Debug, Clone)]
+pub enum Rule {
+    Or(Box<Rule>, Box<Rule>),
+    Char(char),
+    // TODO this should be a vec!
+    Sequence(Vec<usize>),
+}
+
+pub type Rules = HashMap<usize, Rc<Rule>>;
+
+// This is a nice little parser combinator!
+impl Rule {
+    // If the parser fails, it returns None
+    // If it succeeds, it returns Some(remaining_chars)
+    fn parse<'a>(&self, inputs: &Vec<&'a str>, list: &Rules) -> Vec<&'a str> {
+        // Haskell does this SO WELL...
+        let mut ret = vec![];
+        for input in inputs {
+            match self {
+                &Rule::Char(i) => {
+                    if !input.is_empty() && i == input.chars().nth(0).unwrap() {
+                        // Master of overcomplication over here! Works though
+                        ret.push(std::str::from_utf8(&input.as_bytes()[1..]).unwrap());
+                    }
+                }
+                // Clean functional code time :)
+                Rule::Sequence(a) => {
+                    let mut inputs = vec![*input];
+                    for rule in a {
+                        let rule = &list[rule];
+
+                        inputs = rule.parse(&inputs, list);
+                    }
+
+                    for input in inputs {
+                        ret.push(input);
+                    }
+                }
+                Rule::Or(a, b) => {
+                    // Well this is essentially cursed! I think the best way to get out of part 2's
+                    // mess is to return a list of inputs, and when we parse, we have to check against
+                    // each and every one of them...
+                    //
+                    // Seriously, what a mess.
+                    for i in a.parse(&vec![*input], list) {
+                        ret.push(i);
+                    }
+
+                    for i in b.parse(&vec![*input], list) {
+                        ret.push(i);
+                    }
+                }
+            }
+        }
+
+        ret
+    }
+
+    fn parses(&self, input: &str, list: &Rules) -> bool {
+        self.parse(&vec![input], list).contains(&"")
+    }
+
+    fn references(&self) -> Vec<usize> {
+        match self {
+            Rule::Char(_) => vec![],
+            Rule::Sequence(a) => a.clone(),
+            Rule::Or(a, b) => [a.references(), b.references()].concat(),
+        }
+    }
+}
+
+fn one_char(input: &str) -> IResult<&str, Rule> {
+    alt((
+        value(Rule::Char('a'), tag("a")),
+        value(Rule::Char('b'), tag("b")),
+    ))(input)
+}
+
+fn sequence(input: &str) -> IResult<&str, Rule> {
+    map(separated_list1(tag(" "), number), Rule::Sequence)(input)
+}
+
+fn rule(input: &str) -> IResult<&str, Rule> {
+    alt((
+        delimited(tag("\""), one_char, tag("\"")),
+        map(separated_pair(sequence, tag(" | "), sequence), |(a, b)| {
+            Rule::Or(Box::new(a), Box::new(b))
+        }),
+        sequence,
+    ))(input)
+}
+
+fn numbered_rule(input: &str) -> IResult<&str, (usize, Rule)> {
+    separated_pair(number, tag(": "), rule)(input)
+}
+
+fn count_valid(rules: &Rules, msgs: &[String]) -> usize {
+    let rule0 = &rules[&0];
+    let mut count = 0;
+
+    for msg in msgs {
+        if rule0.parses(msg, rules) {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+pub struct Day19;
+
+pub const DAY: Day = Day::new::<Day19>(19);
+
+impl Solution for Day19 {
+    type Input = (Rules, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut rules = Rules::new();
+        let mut defined = vec![];
+        let mut lines = input.lines();
+        let mut end = &input[input.len()..];
+
+        // The rules come first, then a blank line, then the messages
+        for line in &mut lines {
+            if line.is_empty() {
+                end = line;
+                break;
+            }
+
+            let (i, rule) = parse_all(input, line, "a rule", numbered_rule)?;
+            defined.push((line, i));
+            rules.insert(i, Rc::new(rule));
+        }
+
+        // Matching would fail halfway through on a rule that isn't there
+        for (line, i) in defined {
+            for n in rules[&i].references() {
+                if !rules.contains_key(&n) {
+                    let n = n.to_string();
+                    let at = line.split(' ').skip(1).find(|&t| t == n).unwrap_or(line);
+                    return Err(ParseError::at(input, at, format!("there is no rule {}", n)));
+                }
+            }
+        }
+
+        if !rules.contains_key(&0) {
+            return Err(ParseError::at(input, end, "expected a rule 0"));
+        }
+
+        let msgs = lines
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect();
+
+        Ok((rules, msgs))
+    }
+
+    fn part1((rules, msgs): &Self::Input) -> Answer {
+        count_valid(rules, msgs).into()
+    }
+
+    fn part2((rules, msgs): &Self::Input) -> Answer {
+        let mut rules = rules.clone();
+
+        rules.insert(8, Rc::new(rule("42 | 42 8").unwrap().1));
+        rules.insert(11, Rc::new(rule("42 31 | 42 11 31").unwrap().1));
+
+        count_valid(&rules, msgs).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE1: &str = "\
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb";
+
+    const EXAMPLE2: &str = "\
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(Day19::part1(&Day19::parse(EXAMPLE1).unwrap()), Answer::Number(2));
+    }
+
+    #[test]
+    fn part1_ignores_loops() {
+        assert_eq!(Day19::part1(&Day19::parse(EXAMPLE2).unwrap()), Answer::Number(3));
+    }
+
+    #[test]
+    fn rejects_missing_rules() {
+        let err = Day19::parse("0: 1 2\n1: \"a\"\n\nab").unwrap_err();
+        assert_eq!((err.line, err.column, err.message.as_str()), (1, 6, "there is no rule 2"));
+
+        let err = Day19::parse("1: \"a\"\n\na").unwrap_err();
+        assert_eq!((err.line, err.message.as_str()), (2, "expected a rule 0"));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(Day19::part2(&Day19::parse(EXAMPLE2).unwrap()), Answer::Number(12));
+    }
+}
@@ -0,0 +1,475 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::{Answer, Day, Grid, ParseError, Solution};
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete::line_ending,
+    combinator::recognize,
+    multi::separated_list1,
+    sequence::delimited,
+    sequence::pair,
+    sequence::separated_pair,
+    IResult,
+};
+use std::collections::{HashMap, HashSet};
+
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+
+/// Turns a grid a quarter turn clockwise.
+fn rotate(grid: &Grid<bool>) -> Grid<bool> {
+    let height = grid.height();
+    Grid::from_fn(height, grid.width(), |x, y| grid[(y, height - 1 - x)])
+}
+
+/// Mirrors a grid left to right.
+fn flip(grid: &Grid<bool>) -> Grid<bool> {
+    let width = grid.width();
+    Grid::from_fn(width, grid.height(), |x, y| grid[(width - 1 - x, y)])
+}
+
+/// All 8 ways a grid can be turned and flipped.
+fn orientations(grid: &Grid<bool>) -> Vec<Grid<bool>> {
+    let mut ret = vec![];
+
+    for mut grid in [grid.clone(), flip(grid)] {
+        for _ in 0..4 {
+            let next = rotate(&grid);
+            ret.push(grid);
+            grid = next;
+        }
+    }
+
+    ret
+}
+
+type Edge = Vec<bool>;
+
+/// The same edge reads backwards on the other tile if one of them is flipped, so edges are
+/// compared in whichever direction comes first.
+fn canonical(edge: &[bool]) -> Edge {
+    let reversed: Edge = edge.iter().rev().copied().collect();
+    reversed.min(edge.to_vec())
+}
+
+#[derive(Debug, Clone)]
+pub struct Tile {
+    id: usize,
+    pixels: Grid<bool>,
+}
+
+impl Tile {
+    fn top(&self) -> Edge {
+        (0..self.pixels.width())
+            .map(|x| self.pixels[(x, 0)])
+            .collect()
+    }
+
+    fn bottom(&self) -> Edge {
+        let y = self.pixels.height() - 1;
+        (0..self.pixels.width())
+            .map(|x| self.pixels[(x, y)])
+            .collect()
+    }
+
+    fn left(&self) -> Edge {
+        (0..self.pixels.height())
+            .map(|y| self.pixels[(0, y)])
+            .collect()
+    }
+
+    fn right(&self) -> Edge {
+        let x = self.pixels.width() - 1;
+        (0..self.pixels.height())
+            .map(|y| self.pixels[(x, y)])
+            .collect()
+    }
+
+    fn edges(&self) -> [Edge; 4] {
+        [self.top(), self.right(), self.bottom(), self.left()]
+    }
+
+    fn orientations(&self) -> impl Iterator<Item = Tile> + '_ {
+        orientations(&self.pixels)
+            .into_iter()
+            .map(move |pixels| Tile {
+                id: self.id,
+                pixels,
+            })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Jigsaw {
+    tiles: Vec<Tile>,
+    // Which tiles have each edge, by canonical edge
+    edges: HashMap<Edge, Vec<usize>>,
+}
+
+impl Jigsaw {
+    fn new(tiles: Vec<Tile>) -> Self {
+        let mut edges: HashMap<Edge, Vec<usize>> = HashMap::new();
+
+        for (i, tile) in tiles.iter().enumerate() {
+            for edge in &tile.edges() {
+                edges.entry(canonical(edge)).or_default().push(i);
+            }
+        }
+
+        Self { tiles, edges }
+    }
+
+    /// The other tile that shares an edge with `tile`, if there is one.
+    fn neighbour(&self, tile: &Tile, edge: &[bool]) -> Option<&Tile> {
+        self.edges[&canonical(edge)]
+            .iter()
+            .map(|&i| &self.tiles[i])
+            .find(|t| t.id != tile.id)
+    }
+
+    fn is_border(&self, tile: &Tile, edge: &[bool]) -> bool {
+        self.neighbour(tile, edge).is_none()
+    }
+
+    fn corners(&self) -> Vec<&Tile> {
+        self.tiles
+            .iter()
+            .filter(|tile| {
+                tile.edges()
+                    .iter()
+                    .filter(|e| self.is_border(tile, e))
+                    .count()
+                    == 2
+            })
+            .collect()
+    }
+
+    /// Lays out every tile in the right orientation, row by row.
+    fn assemble(&self) -> Vec<Vec<Tile>> {
+        // Parsing made sure this is a whole number
+        let side = side(self.tiles.len());
+
+        // Any corner can go in the top left, as long as it's turned with its border outwards
+        let corner = self
+            .corners()
+            .into_iter()
+            .next()
+            .expect("There are no corner tiles");
+        let corner = corner
+            .orientations()
+            .find(|t| self.is_border(t, &t.top()) && self.is_border(t, &t.left()))
+            .unwrap();
+
+        let mut rows: Vec<Vec<Tile>> = vec![];
+        let mut row = vec![corner];
+
+        loop {
+            if row.len() == side {
+                rows.push(row);
+                if rows.len() == side {
+                    break;
+                }
+
+                // Start the next row under the first tile of the last one
+                let above = &rows[rows.len() - 1][0];
+                let bottom = above.bottom();
+                let next = self
+                    .neighbour(above, &bottom)
+                    .and_then(|t| t.orientations().find(|t| t.top() == bottom))
+                    .unwrap_or_else(|| panic!("Nothing fits under tile {}", above.id));
+
+                row = vec![next];
+            } else {
+                let left = &row[row.len() - 1];
+                let right = left.right();
+                let next = self
+                    .neighbour(left, &right)
+                    .and_then(|t| t.orientations().find(|t| t.left() == right))
+                    .unwrap_or_else(|| panic!("Nothing fits right of tile {}", left.id));
+
+                row.push(next);
+            }
+        }
+
+        rows
+    }
+
+    /// The assembled image, without the borders of the tiles.
+    fn image(&self) -> Grid<bool> {
+        let rows = self.assemble();
+        let inner = rows[0][0].pixels.width() - 2;
+        let size = rows.len() * inner;
+
+        Grid::from_fn(size, size, |x, y| {
+            rows[y / inner][x / inner].pixels[(x % inner + 1, y % inner + 1)]
+        })
+    }
+}
+
+/// How many tiles a square image made of `tiles` tiles is across, rounded up.
+fn side(tiles: usize) -> usize {
+    (1..).find(|n| n * n >= tiles).unwrap()
+}
+
+/// Counts the rough water: every `#` that isn't part of a sea monster, once the image is turned
+/// so that there are any.
+fn roughness(image: &Grid<bool>) -> usize {
+    let monster: Vec<(usize, usize)> = MONSTER
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.chars()
+                .enumerate()
+                .filter(|&(_, c)| c == '#')
+                .map(move |(x, _)| (x, y))
+        })
+        .collect();
+    let (width, height) = (MONSTER[0].len(), MONSTER.len());
+
+    for image in orientations(image) {
+        let mut monsters = HashSet::new();
+
+        for y in 0..=image.height().saturating_sub(height) {
+            for x in 0..=image.width().saturating_sub(width) {
+                let cells = monster.iter().map(|&(dx, dy)| (x + dx, y + dy));
+
+                if cells.clone().all(|(x, y)| image.get(x, y) == Some(&true)) {
+                    monsters.extend(cells);
+                }
+            }
+        }
+
+        if !monsters.is_empty() {
+            return image.cells().filter(|&&c| c).count() - monsters.len();
+        }
+    }
+
+    panic!("There are no sea monsters")
+}
+
+fn parse_tile(input: &str) -> IResult<&str, (usize, &str)> {
+    separated_pair(
+        delimited(tag("Tile "), number, tag(":")),
+        line_ending,
+        recognize(separated_list1(line_ending, is_a("#."))),
+    )(input)
+}
+
+fn parse_tiles(input: &str) -> IResult<&str, Vec<(usize, &str)>> {
+    separated_list1(pair(line_ending, line_ending), parse_tile)(input)
+}
+
+fn parse_pixels(input: &str, pixels: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(pixels, |c| Some(c == '#')).map_err(|err| {
+        // The grid only knows about its own lines, so move the error to where the tile starts
+        let offset = pixels.as_ptr() as usize - input.as_ptr() as usize;
+        ParseError {
+            line: err.line + input[..offset].matches('\n').count(),
+            ..err
+        }
+    })
+}
+
+pub struct Day20;
+
+pub const DAY: Day = Day::new::<Day20>(20);
+
+impl Solution for Day20 {
+    type Input = Jigsaw;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut tiles = vec![];
+
+        for (id, pixels) in parse_all(input, input, "a tile", parse_tiles)? {
+            let grid = parse_pixels(input, pixels)?;
+
+            let size = tiles
+                .first()
+                .map_or(grid.width(), |t: &Tile| t.pixels.width());
+            if grid.width() != size || grid.height() != size || size < 3 {
+                return Err(ParseError::at(
+                    input,
+                    pixels,
+                    format!("expected a tile of {0}x{0} pixels", size.max(3)),
+                ));
+            }
+
+            tiles.push(Tile { id, pixels: grid });
+        }
+
+        let side = side(tiles.len());
+        if side * side != tiles.len() || side < 2 {
+            let end = &input[input.trim_end().len()..];
+            return Err(ParseError::at(
+                input,
+                end,
+                "expected a square number of tiles, at least 4",
+            ));
+        }
+
+        Ok(Jigsaw::new(tiles))
+    }
+
+    fn part1(input: &Self::Input) -> Answer {
+        input
+            .corners()
+            .iter()
+            .map(|t| t.id)
+            .product::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input) -> Answer {
+        roughness(&input.image()).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Tile 2311:
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###
+
+Tile 1951:
+#.##...##.
+#.####...#
+.....#..##
+#...######
+.##.#....#
+.###.#####
+###.##.##.
+.###....#.
+..#.#..#.#
+#...##.#..
+
+Tile 1171:
+####...##.
+#..##.#..#
+##.#..#.#.
+.###.####.
+..###.####
+.##....##.
+.#...####.
+#.##.####.
+####..#...
+.....##...
+
+Tile 1427:
+###.##.#..
+.#..#.##..
+.#.##.#..#
+#.#.#.##.#
+....#...##
+...##..##.
+...#.#####
+.#.####.#.
+..#..###.#
+..##.#..#.
+
+Tile 1489:
+##.#.#....
+..##...#..
+.##..##...
+..#...#...
+#####...#.
+#..#.#.#.#
+...#.#.#..
+##.#...##.
+..##.##.##
+###.##.#..
+
+Tile 2473:
+#....####.
+#..#.##...
+#.##..#...
+######.#.#
+.#...#.#.#
+.#########
+.###.#..#.
+########.#
+##...##.#.
+..###.#.#.
+
+Tile 2971:
+..#.#....#
+#...###...
+#.#.###...
+##.##..#..
+.#####..##
+.#..####.#
+#..#.#..#.
+..####.###
+..#.#.###.
+...#.#.#.#
+
+Tile 2729:
+...#.#.#.#
+####.#....
+..#.#.....
+....#..#.#
+.##..##.#.
+.#.####...
+####.#.#..
+##.####...
+##..#.##..
+#.##...##.
+
+Tile 3079:
+#.#.#####.
+.#..######
+..#.......
+######....
+####.#..#.
+.#...#.##.
+#.#####.##
+..#.###...
+..#.......
+..#.###...
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day20::part1(&Day20::parse(EXAMPLE).unwrap()),
+            Answer::Number(20899048083289)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day20::part2(&Day20::parse(EXAMPLE).unwrap()),
+            Answer::Number(273)
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_tiles() {
+        let err = Day20::parse("Tile 1:\n#.#\n.#\n#.#\n").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[test]
+    fn rejects_tiles_that_cant_make_a_square() {
+        let tiles = EXAMPLE
+            .split("\n\n")
+            .take(3)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        let err = Day20::parse(&tiles).unwrap_err();
+        assert_eq!(err.message, "expected a square number of tiles, at least 4");
+    }
+}
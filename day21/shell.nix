@@ -0,0 +1,5 @@
+with (import <nixpkgs> {});
+mkShell {
+  name = "aoc20-day21";
+  buildInputs = [ cargo rustc rustfmt ];
+}
@@ -0,0 +1,174 @@
+use aoc_common::parsers::parse_all;
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    bytes::complete::tag, character::complete::alpha1, character::complete::line_ending,
+    combinator::opt, multi::separated_list1, sequence::delimited, sequence::pair, IResult,
+};
+use std::collections::{BTreeMap, HashSet};
+
+#[derive(Debug, Clone)]
+pub struct Food {
+    ingredients: Vec<String>,
+    allergens: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Foods {
+    foods: Vec<Food>,
+}
+
+impl Foods {
+    /// For every allergen, the ingredients that are in every food that lists it.
+    fn candidates(&self) -> BTreeMap<&str, Vec<&str>> {
+        let mut ret: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+
+        for food in &self.foods {
+            let ingredients: Vec<&str> = food.ingredients.iter().map(String::as_str).collect();
+
+            for allergen in &food.allergens {
+                ret.entry(allergen)
+                    .and_modify(|c| c.retain(|i| ingredients.contains(i)))
+                    .or_insert_with(|| ingredients.clone());
+            }
+        }
+
+        ret
+    }
+
+    fn count_safe(&self) -> usize {
+        let unsafe_ingredients: HashSet<&str> = self.candidates().into_values().flatten().collect();
+
+        self.foods
+            .iter()
+            .flat_map(|f| &f.ingredients)
+            .filter(|i| !unsafe_ingredients.contains(i.as_str()))
+            .count()
+    }
+
+    /// Works out which ingredient has each allergen, sorted by allergen.
+    fn match_allergens(&self) -> Vec<(&str, &str)> {
+        let mut possible_ingredients = self.candidates();
+        let mut done: Vec<&str> = vec![];
+
+        while done.len() != possible_ingredients.len() {
+            // An allergen with a single candidate rules that ingredient out for every other one
+            let found = possible_ingredients
+                .iter()
+                .find(|(a, c)| !done.contains(a) && c.len() == 1)
+                .map(|(&a, c)| (a, c[0]));
+
+            let (allergen, needle) = match found {
+                Some(found) => found,
+                None => panic!("The allergens can't be narrowed down to one ingredient each"),
+            };
+            done.push(allergen);
+
+            for (a, candidates) in &mut possible_ingredients {
+                if *a != allergen {
+                    candidates.retain(|&c| c != needle);
+                }
+            }
+        }
+
+        possible_ingredients
+            .into_iter()
+            .map(|(a, c)| (a, c[0]))
+            .collect()
+    }
+
+    fn dangerous_ingredients(&self) -> String {
+        let matches = self.match_allergens();
+
+        matches
+            .iter()
+            .map(|(_, i)| *i)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+fn words<'a>(separator: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<String>> {
+    move |input| {
+        let (input, words) = separated_list1(tag(separator), alpha1)(input)?;
+
+        Ok((input, words.into_iter().map(String::from).collect()))
+    }
+}
+
+fn food(input: &str) -> IResult<&str, Food> {
+    let (input, (ingredients, allergens)) = pair(
+        words(" "),
+        opt(delimited(tag(" (contains "), words(", "), tag(")"))),
+    )(input)?;
+
+    Ok((
+        input,
+        Food {
+            ingredients,
+            allergens: allergens.unwrap_or_default(),
+        },
+    ))
+}
+
+fn foods(input: &str) -> IResult<&str, Foods> {
+    let (input, foods) = separated_list1(line_ending, food)(input)?;
+
+    Ok((input, Foods { foods }))
+}
+
+pub struct Day21;
+
+pub const DAY: Day = Day::new::<Day21>(21);
+
+impl Solution for Day21 {
+    type Input = Foods;
+
+    fn parse(input: &str) -> Result<Foods, ParseError> {
+        parse_all(input, input, "a list of ingredients", foods)
+    }
+
+    fn part1(foods: &Foods) -> Answer {
+        foods.count_safe().into()
+    }
+
+    fn part2(foods: &Foods) -> Answer {
+        foods.dangerous_ingredients().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+mxmxvkd kfcds sqjhc nhms (contains dairy, fish)
+trh fvjkl sbzzf mxmxvkd (contains dairy)
+sqjhc fvjkl (contains soy)
+sqjhc mxmxvkd sbzzf (contains fish)";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day21::part1(&Day21::parse(EXAMPLE).unwrap()),
+            Answer::Number(5)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day21::part2(&Day21::parse(EXAMPLE).unwrap()),
+            Answer::Text("mxmxvkd,sqjhc,fvjkl".to_string())
+        );
+    }
+
+    #[test]
+    fn matches_allergens() {
+        let foods = Day21::parse(EXAMPLE).unwrap();
+
+        assert_eq!(
+            foods.match_allergens(),
+            vec![("dairy", "mxmxvkd"), ("fish", "sqjhc"), ("soy", "fvjkl")]
+        );
+    }
+}
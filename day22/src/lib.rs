@@ -0,0 +1,248 @@
+use aoc_common::parsers::{number, parse_all};
+use aoc_common::progress;
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{
+    bytes::complete::tag, character::complete::digit1, character::complete::line_ending,
+    multi::separated_list1, sequence::pair, sequence::preceded, sequence::separated_pair,
+    sequence::tuple, IResult,
+};
+use std::collections::{HashSet, VecDeque};
+
+type Deck = VecDeque<usize>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Player {
+    One,
+    Two,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Decks {
+    player1: Deck,
+    player2: Deck,
+}
+
+impl Decks {
+    fn winner(&self) -> Option<Player> {
+        if self.player2.is_empty() {
+            Some(Player::One)
+        } else if self.player1.is_empty() {
+            Some(Player::Two)
+        } else {
+            None
+        }
+    }
+
+    fn deck(&self, player: Player) -> &Deck {
+        match player {
+            Player::One => &self.player1,
+            Player::Two => &self.player2,
+        }
+    }
+
+    fn draw(&mut self) -> (usize, usize) {
+        (
+            self.player1.pop_front().unwrap(),
+            self.player2.pop_front().unwrap(),
+        )
+    }
+
+    /// Gives both cards of a round to whoever won it, their own card first.
+    fn give(&mut self, winner: Player, (card1, card2): (usize, usize)) {
+        match winner {
+            Player::One => self.player1.extend(&[card1, card2]),
+            Player::Two => self.player2.extend(&[card2, card1]),
+        }
+    }
+
+    fn score(&self, player: Player) -> usize {
+        let deck = self.deck(player);
+        deck.iter()
+            .enumerate()
+            .map(|(i, card)| card * (deck.len() - i))
+            .sum()
+    }
+}
+
+fn combat(decks: &Decks) -> (Player, Decks) {
+    let mut decks = decks.clone();
+    let mut round = 0u64;
+
+    loop {
+        if round % (1 << 10) == 0 {
+            progress::report(round, None);
+        }
+        round += 1;
+
+        if let Some(winner) = decks.winner() {
+            return (winner, decks);
+        }
+
+        let (card1, card2) = decks.draw();
+        let winner = if card1 > card2 {
+            Player::One
+        } else {
+            Player::Two
+        };
+        decks.give(winner, (card1, card2));
+    }
+}
+
+/// One game of recursive combat that's still being played.
+struct Game {
+    decks: Decks,
+    seen: HashSet<Decks>,
+    // The cards of the round that's waiting on a sub-game to finish
+    drawn: Option<(usize, usize)>,
+}
+
+impl Game {
+    fn new(decks: Decks) -> Self {
+        Self {
+            decks,
+            seen: HashSet::new(),
+            drawn: None,
+        }
+    }
+}
+
+fn recursive_combat(decks: &Decks) -> (Player, Decks) {
+    // Games can nest very deeply, so they're kept on a stack of our own instead of recursing
+    let mut games = vec![Game::new(decks.clone())];
+    let mut sub_winner = None;
+    let mut round = 0u64;
+
+    loop {
+        if round % (1 << 10) == 0 {
+            progress::report(round, None);
+        }
+        round += 1;
+
+        let game = games.last_mut().unwrap();
+
+        let (winner, cards) = match (sub_winner.take(), game.drawn.take()) {
+            (Some(winner), Some(cards)) => (winner, cards),
+            _ => {
+                // Repeating an earlier round ends the game in player 1's favour
+                let over = if game.seen.insert(game.decks.clone()) {
+                    game.decks.winner()
+                } else {
+                    Some(Player::One)
+                };
+
+                if let Some(winner) = over {
+                    let game = games.pop().unwrap();
+                    if games.is_empty() {
+                        return (winner, game.decks);
+                    }
+
+                    sub_winner = Some(winner);
+                    continue;
+                }
+
+                let (card1, card2) = game.decks.draw();
+
+                if card1 <= game.decks.player1.len() && card2 <= game.decks.player2.len() {
+                    let decks = Decks {
+                        player1: game.decks.player1.iter().take(card1).copied().collect(),
+                        player2: game.decks.player2.iter().take(card2).copied().collect(),
+                    };
+
+                    game.drawn = Some((card1, card2));
+                    games.push(Game::new(decks));
+                    continue;
+                }
+
+                let winner = if card1 > card2 {
+                    Player::One
+                } else {
+                    Player::Two
+                };
+                (winner, (card1, card2))
+            }
+        };
+
+        games.last_mut().unwrap().decks.give(winner, cards);
+    }
+}
+
+fn player(input: &str) -> IResult<&str, Deck> {
+    let (input, cards) = preceded(
+        tuple((tag("Player "), digit1, tag(":"), line_ending)),
+        separated_list1(line_ending, number),
+    )(input)?;
+
+    Ok((input, cards.into_iter().collect()))
+}
+
+fn decks(input: &str) -> IResult<&str, Decks> {
+    let (input, (player1, player2)) =
+        separated_pair(player, pair(line_ending, line_ending), player)(input)?;
+
+    Ok((input, Decks { player1, player2 }))
+}
+
+pub struct Day22;
+
+pub const DAY: Day = Day::new::<Day22>(22);
+
+impl Solution for Day22 {
+    type Input = Decks;
+
+    fn parse(input: &str) -> Result<Decks, ParseError> {
+        parse_all(input, input, "the decks of both players", decks)
+    }
+
+    fn part1(decks: &Decks) -> Answer {
+        let (winner, decks) = combat(decks);
+        decks.score(winner).into()
+    }
+
+    fn part2(decks: &Decks) -> Answer {
+        let (winner, decks) = recursive_combat(decks);
+        decks.score(winner).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+Player 1:
+9
+2
+6
+3
+1
+
+Player 2:
+5
+8
+4
+7
+10";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day22::part1(&Day22::parse(EXAMPLE).unwrap()),
+            Answer::Number(306)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day22::part2(&Day22::parse(EXAMPLE).unwrap()),
+            Answer::Number(291)
+        );
+    }
+
+    #[test]
+    fn infinite_games_end() {
+        let decks = Day22::parse("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14\n").unwrap();
+
+        assert_eq!(recursive_combat(&decks).0, Player::One);
+    }
+}
@@ -0,0 +1,195 @@
+use aoc_common::progress;
+use aoc_common::{Answer, Day, ParseError, Solution};
+
+/// The cups in a circle, kept as a linked list in an array. Cups are numbered from 0, one less
+/// than their labels, and `next[cup]` is the cup clockwise of `cup`.
+///
+/// Each move of part 2 follows four links in a row through a million cups, so it's waiting on
+/// memory most of the time. Links take three bytes each instead of four, which is plenty for
+/// fewer than 2^24 cups, so that more of them stay in the cache.
+#[derive(Debug)]
+struct Cups {
+    next: Vec<[u8; 3]>,
+    current: u32,
+}
+
+impl Cups {
+    /// Lays out the labels in order, followed by every label after the largest one up to `total`.
+    fn new(labels: &[u32], total: u32) -> Self {
+        let max = labels.iter().copied().max().unwrap_or(0);
+        assert!(total.max(max) < 1 << 24, "too many cups");
+
+        let mut order = labels.iter().copied().chain(max + 1..=total).map(|l| l - 1);
+        let first = order.next().unwrap_or(0);
+
+        let mut cups = Self {
+            next: vec![[0; 3]; total.max(max) as usize],
+            current: first,
+        };
+
+        let mut last = first;
+        for cup in order {
+            cups.link(last, cup);
+            last = cup;
+        }
+        cups.link(last, first);
+
+        cups
+    }
+
+    /// The cup clockwise of `cup`.
+    fn next(&self, cup: u32) -> u32 {
+        let [a, b, c] = self.next[cup as usize];
+        u32::from_le_bytes([a, b, c, 0])
+    }
+
+    fn link(&mut self, cup: u32, next: u32) {
+        let [a, b, c, _] = next.to_le_bytes();
+        self.next[cup as usize] = [a, b, c];
+    }
+
+    fn step(&mut self) {
+        let last = self.next.len() as u32 - 1;
+        let current = self.current;
+
+        let a = self.next(current);
+        let b = self.next(a);
+        let c = self.next(b);
+        self.link(current, self.next(c));
+
+        let mut destination = current;
+        loop {
+            destination = if destination == 0 {
+                last
+            } else {
+                destination - 1
+            };
+
+            if destination != a && destination != b && destination != c {
+                break;
+            }
+        }
+
+        self.link(c, self.next(destination));
+        self.link(destination, a);
+        self.current = self.next(current);
+    }
+
+    /// The labels clockwise of `label`, going round once.
+    fn after(&self, label: u32) -> impl Iterator<Item = u32> + '_ {
+        let mut cup = label - 1;
+
+        std::iter::from_fn(move || {
+            cup = self.next(cup);
+            Some(cup + 1).filter(|&next| next != label)
+        })
+    }
+}
+
+fn play(labels: &[u32], total: u32, moves: usize) -> Cups {
+    let mut cups = Cups::new(labels, total);
+
+    for i in 0..moves {
+        if i % (1 << 16) == 0 {
+            progress::report(i as u64, Some(moves as u64));
+        }
+
+        cups.step();
+    }
+
+    cups
+}
+
+pub struct Day23;
+
+pub const DAY: Day = Day::new::<Day23>(23);
+
+impl Solution for Day23 {
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        let labels = input.trim();
+        let count = labels.chars().count() as u32;
+        let mut ret = vec![];
+
+        for (i, c) in labels.char_indices() {
+            let label = c
+                .to_digit(10)
+                .filter(|&l| l >= 1 && l <= count)
+                .ok_or_else(|| {
+                    ParseError::at(
+                        input,
+                        &labels[i..],
+                        format!("expected a cup from 1 to {}", count),
+                    )
+                })?;
+
+            if ret.contains(&label) {
+                let message = "this cup is already in the circle";
+                return Err(ParseError::at(input, &labels[i..], message));
+            }
+
+            ret.push(label);
+        }
+
+        if ret.len() < 5 {
+            return Err(ParseError::at(input, labels, "expected at least 5 cups"));
+        }
+
+        Ok(ret)
+    }
+
+    fn part1(labels: &Vec<u32>) -> Answer {
+        let cups = play(labels, labels.len() as u32, 100);
+        cups.after(1)
+            .map(|l| l.to_string())
+            .collect::<String>()
+            .into()
+    }
+
+    fn part2(labels: &Vec<u32>) -> Answer {
+        let cups = play(labels, 1_000_000, 10_000_000);
+        cups.after(1).take(2).map(u64::from).product::<u64>().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "389125467";
+
+    #[test]
+    fn ten_moves() {
+        let cups = play(&Day23::parse(EXAMPLE).unwrap(), 9, 10);
+        assert_eq!(
+            cups.after(1).collect::<Vec<_>>(),
+            vec![9, 2, 6, 5, 8, 3, 7, 4]
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day23::part1(&Day23::parse(EXAMPLE).unwrap()),
+            Answer::Text("67384529".to_string())
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day23::part2(&Day23::parse(EXAMPLE).unwrap()),
+            Answer::Number(149245887792)
+        );
+    }
+
+    #[test]
+    fn rejects_bad_cups() {
+        let err = Day23::parse("38912546\n").unwrap_err();
+        assert_eq!(err.column, 3);
+
+        let err = Day23::parse("3891254673").unwrap_err();
+        assert_eq!(err.column, 10);
+    }
+}
@@ -0,0 +1,194 @@
+use aoc_common::parsers::parse_all;
+use aoc_common::progress;
+use aoc_common::{Answer, Day, ParseError, Solution};
+use nom::{branch::alt, bytes::complete::tag, combinator::value, multi::many1, IResult};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+const DIRECTIONS: [Direction; 6] = [
+    Direction::East,
+    Direction::SouthEast,
+    Direction::SouthWest,
+    Direction::West,
+    Direction::NorthWest,
+    Direction::NorthEast,
+];
+
+/// A hexagonal tile in axial coordinates: `q` grows to the east, and `r` to the south east.
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, Default)]
+struct Coordinate(i64, i64);
+
+impl Coordinate {
+    fn step(self, direction: Direction) -> Coordinate {
+        let Coordinate(q, r) = self;
+
+        match direction {
+            Direction::East => Coordinate(q + 1, r),
+            Direction::SouthEast => Coordinate(q, r + 1),
+            Direction::SouthWest => Coordinate(q - 1, r + 1),
+            Direction::West => Coordinate(q - 1, r),
+            Direction::NorthWest => Coordinate(q, r - 1),
+            Direction::NorthEast => Coordinate(q + 1, r - 1),
+        }
+    }
+
+    fn neighbours(self) -> impl Iterator<Item = Coordinate> {
+        DIRECTIONS.iter().map(move |&d| self.step(d))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Floor {
+    black: HashSet<Coordinate>,
+}
+
+impl Floor {
+    /// Flips the tile at the end of every path, starting from the reference tile each time.
+    fn new(paths: &[Vec<Direction>]) -> Self {
+        let mut black = HashSet::new();
+
+        for path in paths {
+            let tile = path.iter().fold(Coordinate::default(), |c, &d| c.step(d));
+
+            if !black.remove(&tile) {
+                black.insert(tile);
+            }
+        }
+
+        Self { black }
+    }
+
+    fn with_neighbours(&self) -> HashSet<Coordinate> {
+        let mut ret = self.black.clone();
+
+        for coord in &self.black {
+            ret.extend(coord.neighbours());
+        }
+
+        ret
+    }
+
+    fn step(&mut self) {
+        let mut ret = HashSet::new();
+
+        for coord in self.with_neighbours() {
+            let black_neighbours = coord
+                .neighbours()
+                .filter(|c| self.black.contains(c))
+                .count();
+
+            let black = if self.black.contains(&coord) {
+                black_neighbours == 1 || black_neighbours == 2
+            } else {
+                black_neighbours == 2
+            };
+
+            if black {
+                ret.insert(coord);
+            }
+        }
+
+        self.black = ret;
+    }
+}
+
+fn path(input: &str) -> IResult<&str, Vec<Direction>> {
+    many1(alt((
+        value(Direction::SouthEast, tag("se")),
+        value(Direction::SouthWest, tag("sw")),
+        value(Direction::NorthWest, tag("nw")),
+        value(Direction::NorthEast, tag("ne")),
+        value(Direction::East, tag("e")),
+        value(Direction::West, tag("w")),
+    )))(input)
+}
+
+pub struct Day24;
+
+pub const DAY: Day = Day::new::<Day24>(24);
+
+impl Solution for Day24 {
+    type Input = Floor;
+
+    fn parse(input: &str) -> Result<Floor, ParseError> {
+        let paths = input
+            .lines()
+            .map(|line| parse_all(input, line, "a direction", path))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Floor::new(&paths))
+    }
+
+    fn part1(floor: &Floor) -> Answer {
+        floor.black.len().into()
+    }
+
+    fn part2(floor: &Floor) -> Answer {
+        let mut floor = floor.clone();
+
+        for day in 0..100 {
+            progress::report(day, Some(100));
+            floor.step();
+        }
+
+        floor.black.len().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day24::part1(&Day24::parse(EXAMPLE).unwrap()),
+            Answer::Number(10)
+        );
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(
+            Day24::part2(&Day24::parse(EXAMPLE).unwrap()),
+            Answer::Number(2208)
+        );
+    }
+
+    #[test]
+    fn paths_loop_back() {
+        let floor = Day24::parse("nwwswee\nnwwswee").unwrap();
+        assert!(floor.black.is_empty());
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::parsers::lines;
+use aoc_common::{Answer, Day, ParseError, Solution};
+use std::collections::HashMap;
+
+const MODULUS: u64 = 20201227;
+const SUBJECT: u64 = 7;
+
+fn pow_mod(base: u64, mut exponent: u64) -> u64 {
+    let mut base = base % MODULUS;
+    let mut ret = 1;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            ret = ret * base % MODULUS;
+        }
+
+        base = base * base % MODULUS;
+        exponent >>= 1;
+    }
+
+    ret
+}
+
+/// Finds the loop size that transforms `subject` into `key`, that is the smallest `x` such that
+/// `subject^x = key (mod 20201227)`, with the baby-step giant-step algorithm.
+fn loop_size(subject: u64, key: u64) -> Option<u64> {
+    // Writing x as i * m + j, store every subject^j, then step key down by subject^m until it
+    // lands on one of them
+    let m = (1..).find(|m| m * m >= MODULUS).unwrap();
+
+    let mut baby_steps = HashMap::new();
+    let mut value = 1;
+    for j in 0..m {
+        baby_steps.entry(value).or_insert(j);
+        value = value * subject % MODULUS;
+    }
+
+    // The modulus is prime, so subject^-m is subject^(modulus - 1 - m)
+    let giant_step = pow_mod(subject, MODULUS - 1 - m);
+    let mut value = key % MODULUS;
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&value) {
+            return Some(i * m + j);
+        }
+
+        value = value * giant_step % MODULUS;
+    }
+
+    None
+}
+
+fn encryption_key(card: u64, door: u64) -> u64 {
+    let card_loop = loop_size(SUBJECT, card)
+        .unwrap_or_else(|| panic!("No loop size gives the public key {}", card));
+
+    pow_mod(door, card_loop)
+}
+
+pub struct Day25;
+
+pub const DAY: Day = Day::new::<Day25>(25);
+
+impl Solution for Day25 {
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), ParseError> {
+        let keys = lines(input, "a public key")?;
+        if let [card, door] = keys[..] {
+            return Ok((card, door));
+        }
+
+        // Point at the first key too many, or where the second one is missing
+        let at = input.lines().nth(2).unwrap_or(&input[input.len()..]);
+        Err(ParseError::at(
+            input,
+            at,
+            "expected exactly two public keys",
+        ))
+    }
+
+    fn part1(&(card, door): &(u64, u64)) -> Answer {
+        encryption_key(card, door).into()
+    }
+
+    // There's no puzzle on the last day, only the star for finishing all the others
+    fn part2(_: &(u64, u64)) -> Answer {
+        Answer::NoPuzzle
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5764801
+17807724";
+
+    #[test]
+    fn finds_loop_sizes() {
+        assert_eq!(loop_size(SUBJECT, 5764801), Some(8));
+        assert_eq!(loop_size(SUBJECT, 17807724), Some(11));
+        assert_eq!(loop_size(SUBJECT, 1), Some(0));
+        assert_eq!(
+            loop_size(SUBJECT, pow_mod(SUBJECT, 12345678)),
+            Some(12345678)
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(
+            Day25::part1(&Day25::parse(EXAMPLE).unwrap()),
+            Answer::Number(14897079)
+        );
+    }
+
+    #[test]
+    fn needs_two_keys() {
+        let err = Day25::parse("5764801\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = Day25::parse("5764801\n17807724\n1\n").unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (3, "1"));
+    }
+}